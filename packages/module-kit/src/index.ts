@@ -1,6 +1,7 @@
 export * from "./pack/pack";
 export * from "./pack/unpack";
 export * from "./pack/integrity";
+export * from "./pack/canonical-manifest";
 export * from "./validate/validate-manifest";
 export * from "./validate/validate-signature";
 export * from "./runtime/runner";
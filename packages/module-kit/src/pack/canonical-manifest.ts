@@ -0,0 +1,51 @@
+import crypto from "node:crypto";
+
+/** Manifest as authored in `module.manifest.json`. */
+export type ManifestJson = {
+  name: string;
+  version: string;
+  description?: string;
+  entryFile: string;
+  runtime: { kind: string; version: string };
+  inputs?: Record<string, string>;
+  outputs?: Record<string, string>;
+};
+
+function u32(n: number): Buffer {
+  const b = Buffer.alloc(4);
+  b.writeUInt32LE(n);
+  return b;
+}
+
+function str(s: string): Buffer {
+  const b = Buffer.from(s, "utf-8");
+  return Buffer.concat([u32(b.length), b]);
+}
+
+// Sorted by UTF-8 bytes to match the program's `String` ordering.
+function fields(map: Record<string, string> = {}): Buffer {
+  const names = Object.keys(map).sort((a, b) => Buffer.compare(Buffer.from(a), Buffer.from(b)));
+  return Buffer.concat([u32(names.length), ...names.flatMap((n) => [str(n), str(map[n])])]);
+}
+
+/**
+ * Encodes a manifest in the registry's canonical form (Borsh, fields in
+ * declaration order, inputs/outputs sorted by name), as decoded on chain
+ * by `Manifest::decode_canonical`.
+ */
+export function encodeCanonicalManifest(manifest: ManifestJson): Buffer {
+  return Buffer.concat([
+    str(manifest.name),
+    str(manifest.version),
+    str(manifest.description ?? ""),
+    str(manifest.entryFile),
+    str(manifest.runtime.kind),
+    str(manifest.runtime.version),
+    fields(manifest.inputs),
+    fields(manifest.outputs),
+  ]);
+}
+
+export function canonicalManifestSha256(manifest: ManifestJson): Buffer {
+  return crypto.createHash("sha256").update(encodeCanonicalManifest(manifest)).digest();
+}
@@ -12,6 +12,10 @@ async function main() {
     [Buffer.from("registry"), authority.toBuffer()],
     program.programId
   );
+  const [treasuryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury"), registryPda.toBuffer()],
+    program.programId
+  );
 
   console.log("Program:", program.programId.toBase58());
   console.log("Authority:", authority.toBase58());
@@ -26,10 +30,30 @@ async function main() {
           allowUnverified: false,
           maxArtifactBytes: new anchor.BN(10_000_000),
           maxRunsPerModule: new anchor.BN(10_000),
+          disputeQuorum: 2,
+          quarantineAfterFailures: 0,
+          runWindowSecs: 3600,
+          maxModuleRunsPerWindow: 600,
+          maxWorkerRunsPerWindow: 600,
+          requireContentAddressedUri: false,
+          maxVersionArtifactBytes: new anchor.BN(0),
+          derivedIds: false,
+          publishFeeLamports: new anchor.BN(0),
+          forkFeeLamports: new anchor.BN(0),
+          runFeeLamports: new anchor.BN(0),
+          feeMint: null,
+          maxRoyaltyBps: 0,
+          royaltyDepth: 0,
+          royaltyDecayBps: 0,
+          minPublishStake: new anchor.BN(0),
+          unstakeCooldownSecs: 0,
+          reputation: { okRun: 0, failedRun: 0, fork: 0, attestation: 0, ageDay: 0, maxAgeDays: 0 },
+          reviewRequiresRun: false,
         },
       })
       .accounts({
         registry: registryPda,
+        treasury: treasuryPda,
         payer: authority,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -0,0 +1,189 @@
+//! Builds the token mapping Merkle tree from a snapshot CSV.
+//!
+//! Usage: `nuttoo-mapping-tree <snapshot.csv> [--denylist <file>]`
+//!
+//! The snapshot has one `holder,amount` row per line (a header row is
+//! skipped); the denylist has one base58 holder per line. Rows for the same
+//! holder are summed, denylisted and zero balances are dropped, and leaves
+//! are ordered by holder. The root, totals and per-holder proofs are written
+//! to stdout as JSON, ready for `init_mapping_distributor` and
+//! `claim_mapped_tokens`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use nuttoo_registry::constants::MAX_MAPPING_LEAVES;
+use nuttoo_registry::utils::{mapping_leaf, merkle_proof, merkle_root};
+
+fn hex(bytes: &[u8; 32]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn read_denylist(path: &str) -> Result<BTreeSet<Pubkey>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+    text.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(|l| Pubkey::from_str(l).map_err(|e| format!("{path}: {l}: {e}")))
+        .collect()
+}
+
+fn read_snapshot(path: &str, denylist: &BTreeSet<Pubkey>) -> Result<BTreeMap<Pubkey, u64>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+    let mut balances = BTreeMap::new();
+    for (n, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (holder, amount) = line
+            .split_once(',')
+            .ok_or_else(|| format!("{path}:{}: expected `holder,amount`", n + 1))?;
+        let Ok(holder) = Pubkey::from_str(holder.trim()) else {
+            if n == 0 {
+                continue; // header row
+            }
+            return Err(format!("{path}:{}: invalid holder", n + 1));
+        };
+        let amount: u64 = amount
+            .trim()
+            .parse()
+            .map_err(|e| format!("{path}:{}: invalid amount: {e}", n + 1))?;
+        if amount == 0 || denylist.contains(&holder) {
+            continue;
+        }
+        let total: &mut u64 = balances.entry(holder).or_default();
+        *total = total
+            .checked_add(amount)
+            .ok_or_else(|| format!("{path}:{}: balance overflow", n + 1))?;
+    }
+    Ok(balances)
+}
+
+fn run(args: &[String]) -> Result<String, String> {
+    let (snapshot, denylist) = match args {
+        [snapshot] => (snapshot, None),
+        [snapshot, flag, denylist] if flag == "--denylist" => (snapshot, Some(denylist)),
+        _ => return Err("usage: nuttoo-mapping-tree <snapshot.csv> [--denylist <file>]".into()),
+    };
+    let denylist = match denylist {
+        Some(path) => read_denylist(path)?,
+        None => BTreeSet::new(),
+    };
+
+    let entries: Vec<(Pubkey, u64)> = read_snapshot(snapshot, &denylist)?.into_iter().collect();
+    if entries.is_empty() || entries.len() > MAX_MAPPING_LEAVES as usize {
+        return Err(format!("snapshot must have 1..={MAX_MAPPING_LEAVES} eligible holders"));
+    }
+
+    let leaves: Vec<[u8; 32]> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, (holder, amount))| mapping_leaf(i as u32, holder, *amount))
+        .collect();
+    let total: u64 = entries
+        .iter()
+        .try_fold(0u64, |acc, (_, amount)| acc.checked_add(*amount))
+        .ok_or("total amount overflows u64")?;
+
+    let claims: Vec<String> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, (holder, amount))| {
+            let proof: Vec<String> = merkle_proof(&leaves, i).iter().map(|p| format!("\"{}\"", hex(p))).collect();
+            format!(
+                "    {{\"index\": {i}, \"holder\": \"{holder}\", \"amount\": {amount}, \"proof\": [{}]}}",
+                proof.join(", ")
+            )
+        })
+        .collect();
+
+    Ok(format!(
+        "{{\n  \"merkle_root\": \"{}\",\n  \"num_leaves\": {},\n  \"total_amount\": {},\n  \"denylist_len\": {},\n  \"claims\": [\n{}\n  ]\n}}",
+        hex(&merkle_root(&leaves)),
+        entries.len(),
+        total,
+        denylist.len(),
+        claims.join(",\n")
+    ))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(json) => {
+            println!("{json}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nuttoo_registry::utils::verify_merkle_proof;
+
+    fn unhex(s: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for (i, b) in out.iter_mut().enumerate() {
+            *b = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    /// Pulls the string or number after `"key": ` out of one output line.
+    fn field<'a>(line: &'a str, key: &str) -> &'a str {
+        let start = line.find(&format!("\"{key}\": ")).unwrap() + key.len() + 4;
+        let rest = &line[start..];
+        let end = rest.find([',', '}']).unwrap();
+        rest[..end].trim_matches('"')
+    }
+
+    #[test]
+    fn tool_proofs_verify_on_chain() {
+        let holders: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let dir = std::env::temp_dir().join(format!("nuttoo-mapping-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let snapshot = dir.join("snapshot.csv");
+        let denylist = dir.join("denylist.txt");
+        let rows: Vec<String> = holders.iter().enumerate().map(|(i, h)| format!("{h},{}", 100 * (i + 1))).collect();
+        fs::write(&snapshot, format!("holder,amount\n{}\n{},7\n", rows.join("\n"), holders[0])).unwrap();
+        fs::write(&denylist, format!("# excluded\n{}\n", holders[4])).unwrap();
+
+        let args = [snapshot.display().to_string(), "--denylist".into(), denylist.display().to_string()];
+        let json = run(&args).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let root_line = json.lines().find(|l| l.contains("\"merkle_root\"")).unwrap();
+        let root = unhex(field(root_line, "merkle_root"));
+        let claims: Vec<&str> = json.lines().filter(|l| l.contains("\"index\"")).collect();
+        assert_eq!(claims.len(), 4);
+
+        for line in claims {
+            let index: u32 = field(line, "index").parse().unwrap();
+            let holder = Pubkey::from_str(field(line, "holder")).unwrap();
+            let amount: u64 = field(line, "amount").parse().unwrap();
+            let proof_start = line.find('[').unwrap() + 1;
+            let proof: Vec<[u8; 32]> = line[proof_start..line.rfind(']').unwrap()]
+                .split(", ")
+                .filter(|p| !p.is_empty())
+                .map(|p| unhex(p.trim_matches('"')))
+                .collect();
+
+            assert_ne!(holder, holders[4]);
+            if holder == holders[0] {
+                assert_eq!(amount, 107);
+            }
+            let leaf = mapping_leaf(index, &holder, amount);
+            assert!(verify_merkle_proof(&root, &leaf, index as u64, &proof));
+            assert!(!verify_merkle_proof(&root, &mapping_leaf(index, &holder, amount + 1), index as u64, &proof));
+        }
+    }
+}
@@ -4,11 +4,59 @@ pub const REGISTRY_SEED: &[u8] = b"registry";
 pub const MODULE_SEED: &[u8] = b"module";
 pub const FORK_SEED: &[u8] = b"fork";
 pub const RUN_SEED: &[u8] = b"run";
+pub const ATTESTER_SEED: &[u8] = b"attester";
+pub const DISPUTE_SEED: &[u8] = b"dispute";
+pub const WORKER_SEED: &[u8] = b"worker";
+pub const RUN_LOG_SEED: &[u8] = b"run_log";
+pub const RUN_BATCH_SEED: &[u8] = b"run_batch";
+pub const ARTIFACT_BUFFER_SEED: &[u8] = b"artifact_buffer";
+pub const VERSION_SEED: &[u8] = b"version";
+pub const ARTIFACT_SEED: &[u8] = b"artifact";
+pub const CATEGORY_SEED: &[u8] = b"category";
+pub const NAME_SEED: &[u8] = b"name";
+pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const ROYALTY_SEED: &[u8] = b"royalty";
+pub const BOUNTY_SEED: &[u8] = b"bounty";
+pub const STAKE_SEED: &[u8] = b"stake";
+pub const MAPPING_SEED: &[u8] = b"mapping";
+pub const EPOCH_SEED: &[u8] = b"epoch";
+pub const USAGE_SEED: &[u8] = b"usage";
+pub const SPONSOR_ESCROW_SEED: &[u8] = b"sponsor_escrow";
+pub const SPONSORSHIP_SEED: &[u8] = b"sponsorship";
+pub const REVIEW_SEED: &[u8] = b"review";
 
 pub const MAX_NAME_LEN: usize = 64;
 pub const MAX_VERSION_LEN: usize = 32;
 pub const MAX_DESC_LEN: usize = 256;
 pub const MAX_URI_LEN: usize = 256;
 pub const MAX_NOTES_LEN: usize = 512;
+pub const MAX_YANK_REASON_LEN: usize = 128;
+pub const MAX_SLASH_REASON_LEN: usize = 128;
+pub const ARWEAVE_TX_ID_LEN: usize = 43;
+pub const MAX_TARGET_LEN: usize = 32;
+pub const MAX_TAGS: usize = 8;
+pub const MAX_TAG_LEN: usize = 24;
+pub const MAX_CATEGORY_SLUG_LEN: usize = 32;
+pub const MAX_RUNTIME_VERSION_LEN: usize = 32;
+pub const MAX_RUNTIME_KIND_LEN: usize = 16;
+pub const MAX_ENTRY_FILE_LEN: usize = 128;
+pub const MAX_MANIFEST_FIELDS: usize = 32;
+pub const MAX_MANIFEST_FIELD_NAME_LEN: usize = 64;
+pub const MAX_MANIFEST_FIELD_TYPE_LEN: usize = 32;
+pub const MAX_MANIFEST_BYTES: usize = 1024;
+pub const MAX_ONCHAIN_ARTIFACT_BYTES: u64 = 8 * 1024;
+pub const MAX_DISPUTE_VOTERS: usize = 16;
+pub const MAX_RUN_LOG_CAPACITY: u32 = 100;
+pub const MAX_RUN_BATCH: usize = 16;
+pub const MAX_MERKLE_DEPTH: usize = 32;
+pub const MAX_ROYALTY_DEPTH: u8 = 8;
+pub const MAX_MAPPING_LEAVES: u32 = 65_536;
+pub const MAX_MAPPING_DENYLIST: usize = 32;
+
+pub const MAX_RUN_PEAK_MEMORY_BYTES: u64 = 64 * 1024 * 1024 * 1024;
+pub const MAX_RUN_CLOCK_SKEW_SECS: i64 = 300;
 
 pub const DEFAULT_BUMP_PADDING: usize = 8;
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const RUN_STATS_WINDOW: u64 = 32;
@@ -25,4 +25,100 @@ pub enum NuttooError {
 
     #[msg("Run limit reached")]
     RunLimitReached,
+
+    #[msg("Runs do not conflict")]
+    RunsAgree,
+
+    #[msg("Dispute is already resolved")]
+    DisputeAlreadyResolved,
+
+    #[msg("Attester has already voted")]
+    AlreadyVoted,
+
+    #[msg("Module is quarantined")]
+    ModuleQuarantined,
+
+    #[msg("Run rate limit exceeded for the current window")]
+    RunRateLimited,
+
+    #[msg("Worker is not active")]
+    WorkerInactive,
+
+    #[msg("Invalid Merkle proof")]
+    InvalidProof,
+
+    #[msg("Artifact buffer is finalized")]
+    ArtifactFinalized,
+
+    #[msg("Id does not match its derived value")]
+    DerivedIdMismatch,
+
+    #[msg("Module version is yanked")]
+    VersionYanked,
+
+    #[msg("Insufficient treasury balance")]
+    InsufficientTreasury,
+
+    #[msg("Fee token accounts missing or invalid")]
+    InvalidFeeAccounts,
+
+    #[msg("Royalty lineage accounts missing or invalid")]
+    InvalidLineage,
+
+    #[msg("Bounty has expired")]
+    BountyExpired,
+
+    #[msg("Bounty has not expired yet")]
+    BountyNotExpired,
+
+    #[msg("Run does not satisfy the bounty")]
+    BountyUnsatisfied,
+
+    #[msg("Insufficient publisher stake")]
+    InsufficientStake,
+
+    #[msg("Stake is locked by live modules")]
+    StakeLocked,
+
+    #[msg("Unstake cooldown has not elapsed")]
+    UnstakeCooldown,
+
+    #[msg("Mapped tokens already claimed")]
+    AlreadyClaimed,
+
+    #[msg("Holder is denylisted")]
+    Denylisted,
+
+    #[msg("Reward epoch is still open")]
+    EpochOpen,
+
+    #[msg("Sponsorship installment is not due yet")]
+    SponsorshipNotDue,
+
+    #[msg("Reviewer has no recorded run of this module version")]
+    ReviewRequiresRun,
+
+    #[msg("Policy parameter out of range")]
+    InvalidPolicy,
+
+    #[msg("Module is retired")]
+    ModuleRetired,
+
+    #[msg("Policy requires a registered worker account")]
+    WorkerRequired,
+
+    #[msg("Only CIDv1 raw-codec sha2-256 CIDs address artifact bytes directly")]
+    UnsupportedCid,
+
+    #[msg("Fork has already been promoted")]
+    ForkAlreadyPromoted,
+
+    #[msg("Module attestation has not been revoked")]
+    AttestationNotRevoked,
+
+    #[msg("Reward epoch has ended")]
+    EpochClosed,
+
+    #[msg("Reward claim window has closed")]
+    ClaimWindowClosed,
 }
@@ -1,53 +1,597 @@
 use anchor_lang::prelude::*;
+use crate::state::{DisputeVerdict, ModuleMeta, ModuleStatus, Policy, RuntimeKind};
 
 #[event]
 pub struct RegistryInitialized {
+    pub event_seq: u64,
     pub authority: Pubkey,
     pub registry: Pubkey,
+    pub policy: Policy,
 }
 
 #[event]
 pub struct PolicyUpdated {
+    pub event_seq: u64,
     pub authority: Pubkey,
     pub registry: Pubkey,
+    pub policy: Policy,
+    pub previous_policy: Policy,
 }
 
 #[event]
 pub struct ModulePublished {
+    pub event_seq: u64,
     pub registry: Pubkey,
     pub module: Pubkey,
     pub module_id: [u8; 32],
     pub owner: Pubkey,
+    pub meta: ModuleMeta,
+    pub manifest_sha256: [u8; 32],
+    pub artifact_sha256: [u8; 32],
+    pub artifact_size: u64,
+    pub verified: bool,
+    pub parent: Pubkey,
+    pub source_fork: Pubkey,
+    pub royalty_bps: u16,
+    pub fee_lamports: u64,
+    pub fee_mint: Option<Pubkey>,
+    pub created_at: i64,
 }
 
 #[event]
 pub struct ModuleUpdated {
+    pub event_seq: u64,
     pub registry: Pubkey,
     pub module: Pubkey,
     pub module_id: [u8; 32],
+    pub meta: ModuleMeta,
+    pub manifest_sha256: [u8; 32],
+    pub previous_meta: ModuleMeta,
+    pub previous_manifest_sha256: [u8; 32],
+    pub updated_at: i64,
 }
 
 #[event]
 pub struct ForkCreated {
+    pub event_seq: u64,
     pub registry: Pubkey,
     pub fork: Pubkey,
     pub fork_id: [u8; 32],
     pub module: Pubkey,
     pub owner: Pubkey,
+    pub version: String,
+    pub module_fork_count: u64,
+    pub fee_lamports: u64,
+    pub fee_mint: Option<Pubkey>,
+    pub created_at: i64,
 }
 
 #[event]
 pub struct ArtifactAttested {
+    pub event_seq: u64,
+    pub registry: Pubkey,
     pub module: Pubkey,
     pub module_id: [u8; 32],
+    pub artifact: Pubkey,
+    pub attester: Pubkey,
     pub artifact_sha256: [u8; 32],
     pub size: u64,
+    pub verified: bool,
+    pub previous_artifact_sha256: [u8; 32],
+    pub previous_size: u64,
+    pub previous_verified: bool,
 }
 
 #[event]
 pub struct RunRecorded {
+    pub event_seq: u64,
+    pub registry: Pubkey,
     pub run: Pubkey,
     pub run_id: [u8; 32],
     pub module: Pubkey,
+    pub fork: Pubkey,
+    pub owner: Pubkey,
+    pub worker: Pubkey,
+    pub module_version: String,
+    pub module_run_count: u64,
+    pub ok: bool,
+    pub exit_code: i32,
+    pub inputs_sha256: [u8; 32],
+    pub outputs_sha256: [u8; 32],
+    pub started_at: i64,
+    pub finished_at: i64,
+    pub wall_time_ms: u64,
+    pub peak_memory_bytes: u64,
+    pub runtime_kind: RuntimeKind,
+    pub fee_lamports: u64,
+    pub fee_mint: Option<Pubkey>,
+}
+
+#[event]
+pub struct AttesterAdded {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub attester: Pubkey,
+}
+
+#[event]
+pub struct AttesterRemoved {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub attester: Pubkey,
+}
+
+#[event]
+pub struct RunDisputed {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub dispute: Pubkey,
+    pub module: Pubkey,
+    pub original_run: Pubkey,
+    pub challenge_run: Pubkey,
+    pub inputs_sha256: [u8; 32],
+    pub challenger: Pubkey,
+    pub fee_lamports: u64,
+    pub fee_mint: Option<Pubkey>,
+}
+
+#[event]
+pub struct RunDisputeResolved {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub dispute: Pubkey,
+    pub original_run: Pubkey,
+    pub challenge_run: Pubkey,
+    pub verdict: DisputeVerdict,
+    pub resolver: Pubkey,
+}
+
+#[event]
+pub struct ModuleStatusChanged {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub module_id: [u8; 32],
+    pub status: ModuleStatus,
+    pub previous_status: ModuleStatus,
+    pub consecutive_failures: u32,
+}
+
+#[event]
+pub struct WorkerRegistered {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub worker: Pubkey,
+}
+
+#[event]
+pub struct WorkerStatusChanged {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub worker: Pubkey,
+    pub active: bool,
+    pub previous_active: bool,
+}
+
+#[event]
+pub struct RunLogInitialized {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub run_log: Pubkey,
+    pub capacity: u32,
+}
+
+#[event]
+pub struct RunLogged {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub run_log: Pubkey,
+    pub seq: u64,
     pub ok: bool,
+    pub exit_code: i32,
+    pub inputs_sha256: [u8; 32],
+    pub outputs_sha256: [u8; 32],
+    pub wall_time_ms: u64,
+    pub fee_lamports: u64,
+    pub fee_mint: Option<Pubkey>,
+}
+
+#[event]
+pub struct RunBatchCommitted {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub run_batch: Pubkey,
+    pub batch_id: [u8; 32],
+    pub merkle_root: [u8; 32],
+    pub count: u32,
+    pub first_run_at: i64,
+    pub last_run_at: i64,
+    pub fee_lamports: u64,
+    pub fee_mint: Option<Pubkey>,
+}
+
+/// Emitted by a read-only proof check, so it takes no `event_seq`.
+#[event]
+pub struct RunInclusionVerified {
+    pub run_batch: Pubkey,
+    pub run_id: [u8; 32],
+    pub index: u32,
+    pub leaf: [u8; 32],
+}
+
+#[event]
+pub struct ArtifactBufferInitialized {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub artifact_buffer: Pubkey,
+    pub size: u32,
+}
+
+#[event]
+pub struct ArtifactFinalized {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub artifact_buffer: Pubkey,
+    pub artifact_sha256: [u8; 32],
+    pub size: u32,
+}
+
+#[event]
+pub struct ArtifactAdded {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub artifact: Pubkey,
+    pub version: String,
+    pub target: String,
+    pub artifact_sha256: [u8; 32],
+    pub size: u64,
+    pub verified: bool,
+}
+
+#[event]
+pub struct CategoryCreated {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub category: Pubkey,
+    pub slug: String,
+}
+
+#[event]
+pub struct ModuleTagged {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub module_id: [u8; 32],
+    pub tags: Vec<String>,
+    pub category: Pubkey,
+    pub previous_tags: Vec<String>,
+    pub previous_category: Pubkey,
+}
+
+#[event]
+pub struct ModuleRenamed {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub module_id: [u8; 32],
+    pub old_name: String,
+    pub new_name: String,
+}
+
+#[event]
+pub struct VersionYanked {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub module_version: Pubkey,
+    pub version: String,
+    pub reason: String,
+}
+
+#[event]
+pub struct VersionUnyanked {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub module_version: Pubkey,
+    pub version: String,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub treasury: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+}
+
+#[event]
+pub struct FeeVaultInitialized {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub treasury: Pubkey,
+    pub fee_mint: Pubkey,
+    pub fee_vault: Pubkey,
+    pub token_program: Pubkey,
+}
+
+#[event]
+pub struct TreasuryTokensWithdrawn {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub treasury: Pubkey,
+    pub fee_mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RoyaltyAccrued {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub source_module: Pubkey,
+    pub royalty_balance: Pubkey,
+    pub level: u8,
+    pub amount: u64,
+    pub accrued: u64,
+}
+
+#[event]
+pub struct RoyaltiesClaimed {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub royalty_balance: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub claimed: u64,
+}
+
+#[event]
+pub struct ModuleRoyaltySet {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub royalty_bps: u16,
+    pub previous_royalty_bps: u16,
+}
+
+#[event]
+pub struct BountyPosted {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub bounty: Pubkey,
+    pub bounty_id: [u8; 32],
+    pub poster: Pubkey,
+    pub module: Pubkey,
+    pub version: String,
+    pub inputs_sha256: [u8; 32],
+    pub mint: Option<Pubkey>,
+    pub reward: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct BountyClaimed {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub bounty: Pubkey,
+    pub bounty_id: [u8; 32],
+    pub run: Pubkey,
+    pub worker: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub reward: u64,
+}
+
+#[event]
+pub struct BountyCancelled {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub bounty: Pubkey,
+    pub bounty_id: [u8; 32],
+    pub poster: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub reward: u64,
+}
+
+#[event]
+pub struct StakeDeposited {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub stake: Pubkey,
+    pub publisher: Pubkey,
+    pub amount: u64,
+    pub total: u64,
+}
+
+#[event]
+pub struct UnstakeRequested {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub stake: Pubkey,
+    pub publisher: Pubkey,
+    pub amount: u64,
+    pub available_at: i64,
+}
+
+#[event]
+pub struct StakeWithdrawn {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub stake: Pubkey,
+    pub publisher: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+}
+
+#[event]
+pub struct PublisherSlashed {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub publisher: Pubkey,
+    pub stake: Pubkey,
+    pub slasher: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+    pub previous_verified: bool,
+    pub reason: String,
+}
+
+#[event]
+pub struct MappingDistributorInitialized {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub distributor: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub snapshot_slot: u64,
+    pub num_leaves: u32,
+    pub total_amount: u64,
+    pub denylist: Vec<Pubkey>,
+}
+
+#[event]
+pub struct MappingDenylistUpdated {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub distributor: Pubkey,
+    pub denylist: Vec<Pubkey>,
+    pub previous_denylist: Vec<Pubkey>,
+}
+
+#[event]
+pub struct MappedTokensClaimed {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub distributor: Pubkey,
+    pub holder: Pubkey,
+    pub index: u32,
+    pub amount: u64,
+    pub claimed_amount: u64,
+}
+
+#[event]
+pub struct RewardEpochOpened {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub reward_epoch: Pubkey,
+    pub index: u64,
+    pub start_at: i64,
+    pub end_at: i64,
+    pub pool: u64,
+    pub run_points: u32,
+    pub fork_points: u32,
+    pub claim_deadline: i64,
+}
+
+#[event]
+pub struct EpochRewardClaimed {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub reward_epoch: Pubkey,
+    pub module: Pubkey,
+    pub owner: Pubkey,
+    pub points: u64,
+    pub total_points: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RewardEpochClosed {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub reward_epoch: Pubkey,
+    pub index: u64,
+    pub claimed_amount: u64,
+    /// Unclaimed pool and rent lamports returned to the authority.
+    pub swept: u64,
+}
+
+#[event]
+pub struct ModuleSponsored {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub sponsor: Pubkey,
+    pub escrow: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub recurring: bool,
+    pub sponsor_total_lamports: u64,
+    pub sponsor_total_tokens: u64,
+}
+
+#[event]
+pub struct SponsorshipScheduled {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub sponsor: Pubkey,
+    pub sponsorship: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount_per_period: u64,
+    pub period_secs: u32,
+    pub periods: u32,
+}
+
+#[event]
+pub struct SponsorshipCancelled {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub sponsor: Pubkey,
+    pub sponsorship: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub refunded: u64,
+}
+
+#[event]
+pub struct SponsorshipWithdrawn {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub escrow: Pubkey,
+    pub owner: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ReputationUpdated {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub module_id: [u8; 32],
+    pub reputation: u64,
+    pub previous_reputation: u64,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct ModuleReviewed {
+    pub event_seq: u64,
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub module_version: Pubkey,
+    pub version: String,
+    pub review: Pubkey,
+    pub reviewer: Pubkey,
+    pub rating: u8,
+    pub flags: u8,
+    pub comment_sha256: [u8; 32],
+    /// `None` for a first review, else the rating it replaced.
+    pub previous_rating: Option<u8>,
+    pub review_count: u32,
+    pub average_rating_x100: u16,
 }
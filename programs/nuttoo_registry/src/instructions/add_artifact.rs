@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AddArtifactInput {
+    pub version: String,
+    pub target: String,
+    pub artifact_sha256: [u8; 32],
+    pub artifact_size: u64,
+    pub uri: String,
+    pub verified: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(input: AddArtifactInput)]
+pub struct AddArtifact<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(mut, has_one = registry)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ModuleVersion::LEN,
+        seeds = [VERSION_SEED, module.key().as_ref(), input.version.as_bytes()],
+        bump
+    )]
+    pub module_version: Account<'info, ModuleVersion>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Artifact::space_for(input.version.len(), input.target.len(), input.uri.len()),
+        seeds = [ARTIFACT_SEED, module_version.key().as_ref(), input.target.as_bytes()],
+        bump
+    )]
+    pub artifact: Account<'info, Artifact>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<AddArtifact>, input: AddArtifactInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    let m = &mut ctx.accounts.module;
+    if input.version != m.meta.version {
+        return err!(NuttooError::InvalidInput);
+    }
+    validate_target(&input.target)?;
+    require_max_len(&input.uri, MAX_URI_LEN)?;
+
+    if input.artifact_size > reg.policy.max_artifact_bytes {
+        return err!(NuttooError::ArtifactTooLarge);
+    }
+
+    if !reg.policy.allow_unverified && !input.verified {
+        return err!(NuttooError::PolicyViolation);
+    }
+
+    check_content_addressed_uri(&reg.policy, &input.uri, &input.artifact_sha256)?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let v = &mut ctx.accounts.module_version;
+    if v.created_at == 0 {
+        v.registry = reg.key();
+        v.module = m.key();
+        v.version = input.version.clone();
+        v.created_at = now;
+        v.artifact_count = 0;
+        v.artifact_bytes = 0;
+        v.bump = ctx.bumps.module_version;
+    }
+    if !v.is_resolvable(false) {
+        return err!(NuttooError::VersionYanked);
+    }
+
+    let total = v.artifact_bytes.saturating_add(input.artifact_size);
+    let cap = reg.policy.max_version_artifact_bytes;
+    if cap > 0 && total > cap {
+        return err!(NuttooError::ArtifactTooLarge);
+    }
+    v.artifact_bytes = total;
+    v.artifact_count = v.artifact_count.saturating_add(1);
+
+    let a = &mut ctx.accounts.artifact;
+    a.registry = reg.key();
+    a.module = m.key();
+    a.module_version = v.key();
+    a.version = input.version;
+    a.target = input.target;
+    a.artifact_sha256 = input.artifact_sha256;
+    a.size = input.artifact_size;
+    a.uri = input.uri;
+    a.verified = input.verified;
+    a.created_at = now;
+    a.updated_at = now;
+    a.bump = ctx.bumps.artifact;
+    m.record_attestation(false, a.verified, &reg.policy.reputation, now);
+
+    emit!(ArtifactAdded {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: m.key(),
+        artifact: a.key(),
+        version: a.version.clone(),
+        target: a.target.clone(),
+        artifact_sha256: a.artifact_sha256,
+        size: a.size,
+        verified: a.verified,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AddAttesterInput {
+    pub attester: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(input: AddAttesterInput)]
+pub struct AddAttester<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Attester::LEN,
+        seeds = [ATTESTER_SEED, registry.key().as_ref(), input.attester.as_ref()],
+        bump
+    )]
+    pub attester: Account<'info, Attester>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<AddAttester>, input: AddAttesterInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    let a = &mut ctx.accounts.attester;
+    a.registry = reg.key();
+    a.attester = input.attester;
+    a.added_at = Clock::get()?.unix_timestamp;
+    a.bump = ctx.bumps.attester;
+
+    emit!(AttesterAdded {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        attester: a.attester,
+    });
+
+    Ok(())
+}
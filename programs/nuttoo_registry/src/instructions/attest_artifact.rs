@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::NuttooError;
 use crate::events::*;
+use crate::utils::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct AttestArtifactInput {
@@ -16,6 +17,11 @@ pub struct AttestArtifact<'info> {
     pub registry: Account<'info, Registry>,
     #[account(mut)]
     pub module: Account<'info, Module>,
+    /// Per-target artifact to attest; when absent the module-level artifact is attested.
+    #[account(mut, has_one = module, has_one = module_version)]
+    pub artifact: Option<Account<'info, Artifact>>,
+    #[account(mut, has_one = module)]
+    pub module_version: Option<Account<'info, ModuleVersion>>,
     pub authority: Signer<'info>,
 }
 
@@ -34,16 +40,69 @@ pub fn handle(ctx: Context<AttestArtifact>, input: AttestArtifactInput) -> Resul
     let m = &mut ctx.accounts.module;
     require_keys_eq!(m.registry, reg.key(), NuttooError::InvalidInput);
 
+    let now = Clock::get()?.unix_timestamp;
+
+    if let Some(a) = ctx.accounts.artifact.as_mut() {
+        let v = ctx.accounts.module_version.as_mut().ok_or(NuttooError::InvalidInput)?;
+
+        check_content_addressed_uri(&reg.policy, &a.uri, &input.artifact_sha256)?;
+
+        let total = v.artifact_bytes.saturating_sub(a.size).saturating_add(input.artifact_size);
+        let cap = reg.policy.max_version_artifact_bytes;
+        if cap > 0 && total > cap {
+            return err!(NuttooError::ArtifactTooLarge);
+        }
+        v.artifact_bytes = total;
+
+        let (previous_artifact_sha256, previous_size, previous_verified) = (a.artifact_sha256, a.size, a.verified);
+
+        a.artifact_sha256 = input.artifact_sha256;
+        a.size = input.artifact_size;
+        a.verified = input.verified;
+        a.updated_at = now;
+        m.record_attestation(previous_verified, a.verified, &reg.policy.reputation, now);
+
+        emit!(ArtifactAttested {
+            event_seq: reg.next_event_seq(),
+            registry: reg.key(),
+            module: m.key(),
+            module_id: m.module_id,
+            artifact: a.key(),
+            attester: ctx.accounts.authority.key(),
+            artifact_sha256: a.artifact_sha256,
+            size: a.size,
+            verified: a.verified,
+            previous_artifact_sha256,
+            previous_size,
+            previous_verified,
+        });
+
+        return Ok(());
+    }
+
+    check_content_addressed_uri(&reg.policy, &m.meta.uri, &input.artifact_sha256)?;
+
+    let (previous_artifact_sha256, previous_size, previous_verified) = (m.artifact_sha256, m.artifact_size, m.verified);
+
     m.artifact_sha256 = input.artifact_sha256;
     m.artifact_size = input.artifact_size;
     m.verified = input.verified;
-    m.updated_at = Clock::get()?.unix_timestamp;
+    m.updated_at = now;
+    m.record_attestation(previous_verified, input.verified, &reg.policy.reputation, now);
 
     emit!(ArtifactAttested {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
         module: m.key(),
         module_id: m.module_id,
+        artifact: Pubkey::default(),
+        attester: ctx.accounts.authority.key(),
         artifact_sha256: m.artifact_sha256,
         size: m.artifact_size,
+        verified: m.verified,
+        previous_artifact_sha256,
+        previous_size,
+        previous_verified,
     });
 
     Ok(())
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct CancelBounty<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        has_one = poster,
+        seeds = [BOUNTY_SEED, registry.key().as_ref(), &bounty.bounty_id],
+        bump = bounty.bump,
+        close = poster
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// Present for token rewards; `counterparty` is the poster's token account.
+    pub escrow_tokens: EscrowTokenAccounts<'info>,
+
+    #[account(mut)]
+    pub poster: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<CancelBounty>) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let b = &ctx.accounts.bounty;
+    require_keys_eq!(b.poster, ctx.accounts.poster.key(), NuttooError::Unauthorized);
+
+    // Workers may rely on an open bounty until it expires.
+    if Clock::get()?.unix_timestamp <= b.expires_at {
+        return err!(NuttooError::BountyNotExpired);
+    }
+
+    let registry_key = reg.key();
+    let seeds: &[&[u8]] = &[BOUNTY_SEED, registry_key.as_ref(), &b.bounty_id, &[b.bump]];
+    let escrow = b.to_account_info();
+    let poster = ctx.accounts.poster.to_account_info();
+    let mut refunded = b.reward;
+    if b.mint.is_some() {
+        let amount = escrow_available(&escrow, b.mint, &ctx.accounts.escrow_tokens)?;
+        refunded = escrow_release(&escrow, seeds, b.mint, &ctx.accounts.escrow_tokens, &poster, amount)?;
+        escrow_close_vault(&escrow, seeds, &ctx.accounts.escrow_tokens, &poster)?;
+    }
+    // Lamport rewards are refunded with the account's rent when it closes.
+
+    emit!(BountyCancelled {
+        event_seq: reg.next_event_seq(),
+        registry: registry_key,
+        bounty: b.key(),
+        bounty_id: b.bounty_id,
+        poster: b.poster,
+        mint: b.mint,
+        reward: refunded,
+    });
+
+    Ok(())
+}
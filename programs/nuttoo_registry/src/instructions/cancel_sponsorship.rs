@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct CancelSponsorship<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        has_one = sponsor,
+        seeds = [SPONSORSHIP_SEED, sponsorship.module.as_ref(), sponsor.key().as_ref()],
+        bump = sponsorship.bump
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+
+    /// Present for token schedules; `counterparty` is the sponsor's token account.
+    /// The emptied vault is closed back to the sponsor.
+    pub escrow_tokens: EscrowTokenAccounts<'info>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<CancelSponsorship>) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let s = &mut ctx.accounts.sponsorship;
+
+    let schedule = s.schedule.take().ok_or(NuttooError::InvalidInput)?;
+    let sponsorship_info = s.to_account_info();
+    let sponsor_info = ctx.accounts.sponsor.to_account_info();
+    // Refund the whole balance rather than the scheduled remainder, which
+    // transfer fees or stray deposits would leave out of step.
+    let available = escrow_available(&sponsorship_info, schedule.mint, &ctx.accounts.escrow_tokens)?;
+    let seeds: &[&[u8]] = &[SPONSORSHIP_SEED, s.module.as_ref(), s.sponsor.as_ref(), &[s.bump]];
+    let refunded = escrow_release(
+        &sponsorship_info,
+        seeds,
+        schedule.mint,
+        &ctx.accounts.escrow_tokens,
+        &sponsor_info,
+        available,
+    )?;
+    if schedule.mint.is_some() {
+        escrow_close_vault(&sponsorship_info, seeds, &ctx.accounts.escrow_tokens, &sponsor_info)?;
+    }
+
+    emit!(SponsorshipCancelled {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: s.module,
+        sponsor: s.sponsor,
+        sponsorship: s.key(),
+        mint: schedule.mint,
+        refunded,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+use crate::instructions::record_run::{write_run, RecordRunInput};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ChallengeRunInput {
+    pub result: RecordRunInput,
+}
+
+#[derive(Accounts)]
+#[instruction(input: ChallengeRunInput)]
+pub struct ChallengeRun<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(mut)]
+    pub module: Account<'info, Module>,
+
+    #[account(mut)]
+    pub original_run: Account<'info, Run>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Run::LEN,
+        seeds = [RUN_SEED, registry.key().as_ref(), &input.result.run_id],
+        bump
+    )]
+    pub challenge_run: Account<'info, Run>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = RunDispute::LEN,
+        seeds = [DISPUTE_SEED, registry.key().as_ref(), original_run.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, RunDispute>,
+
+    /// Registered worker that executed the re-run; required while `policy.limits_workers()`.
+    #[account(mut, has_one = registry)]
+    pub worker: Option<Account<'info, Worker>>,
+
+    /// Registry's active reward epoch; required while one is set.
+    #[account(mut, address = registry.active_epoch)]
+    pub reward_epoch: Option<Account<'info, RewardEpoch>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ModuleUsage::LEN,
+        seeds = [USAGE_SEED, registry.active_epoch.as_ref(), module.key().as_ref()],
+        bump
+    )]
+    pub module_usage: Option<Account<'info, ModuleUsage>>,
+
+    /// Present when the challenger is a registered attester rather than the authority.
+    #[account(
+        seeds = [ATTESTER_SEED, registry.key().as_ref(), challenger.key().as_ref()],
+        bump = attester.bump
+    )]
+    pub attester: Option<Account<'info, Attester>>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        seeds = [TREASURY_SEED, registry.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub fee_tokens: FeeTokenAccounts<'info>,
+
+    pub challenger: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// A challenge re-run is charged and accounted exactly like `record_run`:
+/// the same fee and royalty split, worker window and epoch usage.
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, ChallengeRun<'info>>, input: ChallengeRunInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let challenger = ctx.accounts.challenger.key();
+    if challenger != reg.authority && ctx.accounts.attester.is_none() {
+        return err!(NuttooError::Unauthorized);
+    }
+
+    let m = &mut ctx.accounts.module;
+    require_keys_eq!(m.registry, reg.key(), NuttooError::InvalidInput);
+
+    let original = &mut ctx.accounts.original_run;
+    require_keys_eq!(original.registry, reg.key(), NuttooError::InvalidInput);
+    require_keys_eq!(original.module, m.key(), NuttooError::InvalidInput);
+
+    let result = &input.result;
+    if original.module_version != m.meta.version || original.inputs_sha256 != result.inputs_sha256 {
+        return err!(NuttooError::InvalidInput);
+    }
+    if original.ok == result.ok
+        && original.exit_code == result.exit_code
+        && original.outputs_sha256 == result.outputs_sha256
+    {
+        return err!(NuttooError::RunsAgree);
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+
+    if reg.policy.derived_ids && result.run_id != derive_run_id(&m.key(), &result.inputs_sha256, m.run_count) {
+        return err!(NuttooError::DerivedIdMismatch);
+    }
+
+    m.consume_run_quota(&reg.policy, now)?;
+    let worker_key = Worker::consume_runs(ctx.accounts.worker.as_deref_mut(), &reg.policy, now, 1)?;
+
+    validate_run_metrics(
+        result.started_at,
+        result.finished_at,
+        result.wall_time_ms,
+        result.peak_memory_bytes,
+        &result.runtime_version,
+        now,
+    )?;
+
+    let fee_lamports = reg.policy.run_fee_lamports;
+    collect_run_fee(
+        reg,
+        m,
+        ctx.remaining_accounts,
+        &mut ctx.accounts.treasury,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.fee_tokens,
+        fee_lamports,
+    )?;
+
+    let c = &mut ctx.accounts.challenge_run;
+    write_run(c, reg.key(), m, challenger, worker_key, now, result, ctx.bumps.challenge_run);
+    c.disputed = true;
+    original.disputed = true;
+
+    let d = &mut ctx.accounts.dispute;
+    d.registry = reg.key();
+    d.module = m.key();
+    d.original_run = original.key();
+    d.challenge_run = c.key();
+    d.inputs_sha256 = original.inputs_sha256;
+    d.challenger = challenger;
+    d.created_at = now;
+    d.verdict = None;
+    d.resolved_at = 0;
+    d.votes_original = 0;
+    d.votes_challenge = 0;
+    d.votes_inconclusive = 0;
+    d.voters = Vec::new();
+    d.bump = ctx.bumps.dispute;
+
+    m.run_count = m.run_count.saturating_add(1);
+    m.updated_at = now;
+    let quarantined = m.apply_run(c.ok, c.exit_code, now, &reg.policy);
+
+    accrue_usage(
+        &mut reg.active_epoch,
+        ctx.accounts.reward_epoch.as_mut(),
+        ctx.accounts.module_usage.as_mut(),
+        ctx.bumps.module_usage.unwrap_or_default(),
+        m.key(),
+        UsageKind::Run,
+        now,
+    )?;
+
+    reg.run_count = reg.run_count.saturating_add(1);
+    reg.touch(now);
+
+    emit!(RunDisputed {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        dispute: d.key(),
+        module: d.module,
+        original_run: d.original_run,
+        challenge_run: d.challenge_run,
+        inputs_sha256: d.inputs_sha256,
+        challenger,
+        fee_lamports,
+        fee_mint: reg.policy.fee_mint,
+    });
+
+    if quarantined {
+        emit!(ModuleStatusChanged {
+            event_seq: reg.next_event_seq(),
+            registry: reg.key(),
+            module: m.key(),
+            module_id: m.module_id,
+            status: m.status,
+            previous_status: ModuleStatus::Active,
+            consecutive_failures: m.run_stats.consecutive_failures,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct ClaimBounty<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        has_one = poster,
+        seeds = [BOUNTY_SEED, registry.key().as_ref(), &bounty.bounty_id],
+        bump = bounty.bump,
+        close = poster
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// The run satisfying the bounty.
+    #[account(has_one = registry)]
+    pub run: Account<'info, Run>,
+
+    #[account(
+        has_one = registry,
+        seeds = [WORKER_SEED, registry.key().as_ref(), run.worker.as_ref()],
+        bump = worker.bump
+    )]
+    pub worker: Account<'info, Worker>,
+
+    /// CHECK: the registered worker's wallet, paid the reward.
+    #[account(mut, address = worker.worker)]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: receives the bounty's rent on close.
+    #[account(mut)]
+    pub poster: UncheckedAccount<'info>,
+
+    /// Present for token rewards; `counterparty` is the worker's token account.
+    pub escrow_tokens: EscrowTokenAccounts<'info>,
+}
+
+pub fn handle(ctx: Context<ClaimBounty>) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let b = &ctx.accounts.bounty;
+    let run = &ctx.accounts.run;
+
+    if !ctx.accounts.worker.active {
+        return err!(NuttooError::WorkerInactive);
+    }
+    if !b.is_satisfied_by(run) {
+        return err!(NuttooError::BountyUnsatisfied);
+    }
+
+    let registry_key = reg.key();
+    let seeds: &[&[u8]] = &[BOUNTY_SEED, registry_key.as_ref(), &b.bounty_id, &[b.bump]];
+    let escrow = b.to_account_info();
+    // Token vaults are paid out in full so stray deposits cannot block the close.
+    let amount = match b.mint {
+        Some(_) => escrow_available(&escrow, b.mint, &ctx.accounts.escrow_tokens)?,
+        None => b.reward,
+    };
+    let paid = escrow_release(
+        &escrow,
+        seeds,
+        b.mint,
+        &ctx.accounts.escrow_tokens,
+        &ctx.accounts.recipient.to_account_info(),
+        amount,
+    )?;
+    if b.mint.is_some() {
+        escrow_close_vault(&escrow, seeds, &ctx.accounts.escrow_tokens, &ctx.accounts.poster.to_account_info())?;
+    }
+
+    emit!(BountyClaimed {
+        event_seq: reg.next_event_seq(),
+        registry: registry_key,
+        bounty: b.key(),
+        bounty_id: b.bounty_id,
+        run: run.key(),
+        worker: ctx.accounts.worker.worker,
+        mint: b.mint,
+        reward: paid,
+    });
+
+    Ok(())
+}
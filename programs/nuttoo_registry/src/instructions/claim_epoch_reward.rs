@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct ClaimEpochReward<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        seeds = [EPOCH_SEED, registry.key().as_ref(), &reward_epoch.index.to_le_bytes()],
+        bump = reward_epoch.bump
+    )]
+    pub reward_epoch: Account<'info, RewardEpoch>,
+
+    #[account(has_one = registry)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        mut,
+        seeds = [USAGE_SEED, reward_epoch.key().as_ref(), module.key().as_ref()],
+        bump = module_usage.bump
+    )]
+    pub module_usage: Account<'info, ModuleUsage>,
+
+    /// CHECK: the module owner, who receives the reward.
+    #[account(mut, address = module.owner)]
+    pub owner: UncheckedAccount<'info>,
+}
+
+/// Permissionless: anyone may crank a payout to the module owner.
+pub fn handle(ctx: Context<ClaimEpochReward>) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let e = &mut ctx.accounts.reward_epoch;
+    let u = &mut ctx.accounts.module_usage;
+
+    let now = Clock::get()?.unix_timestamp;
+    if now < e.end_at {
+        return err!(NuttooError::EpochOpen);
+    }
+    if now >= e.claim_deadline {
+        return err!(NuttooError::ClaimWindowClosed);
+    }
+    if u.claimed {
+        return err!(NuttooError::AlreadyClaimed);
+    }
+
+    let amount = e.reward_for(u.points);
+    u.claimed = true;
+    if amount > 0 {
+        let info = e.to_account_info();
+        **info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.owner.try_borrow_mut_lamports()? += amount;
+        e.claimed_amount = e.claimed_amount.saturating_add(amount);
+    }
+
+    emit!(EpochRewardClaimed {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        reward_epoch: e.key(),
+        module: ctx.accounts.module.key(),
+        owner: ctx.accounts.owner.key(),
+        points: u.points,
+        total_points: e.total_points,
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ClaimMappedTokensInput {
+    pub index: u32,
+    pub amount: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimMappedTokens<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        has_one = mint,
+        seeds = [MAPPING_SEED, registry.key().as_ref(), mint.key().as_ref()],
+        bump = distributor.bump
+    )]
+    pub distributor: Account<'info, MappingDistributor>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = distributor,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder,
+        token::token_program = token_program
+    )]
+    pub holder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub holder: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle(ctx: Context<ClaimMappedTokens>, input: ClaimMappedTokensInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let d = &mut ctx.accounts.distributor;
+    let holder = ctx.accounts.holder.key();
+
+    if input.index >= d.num_leaves || input.amount == 0 || input.proof.len() > MAX_MERKLE_DEPTH {
+        return err!(NuttooError::InvalidInput);
+    }
+    if d.is_denylisted(&holder) {
+        return err!(NuttooError::Denylisted);
+    }
+    if d.is_claimed(input.index) {
+        return err!(NuttooError::AlreadyClaimed);
+    }
+
+    let leaf = mapping_leaf(input.index, &holder, input.amount);
+    if !verify_merkle_proof(&d.merkle_root, &leaf, input.index as u64, &input.proof) {
+        return err!(NuttooError::InvalidProof);
+    }
+
+    let registry_key = reg.key();
+    let mint_key = ctx.accounts.mint.key();
+    let seeds: &[&[u8]] = &[MAPPING_SEED, registry_key.as_ref(), mint_key.as_ref(), &[d.bump]];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.holder_token_account.to_account_info(),
+                authority: d.to_account_info(),
+            },
+            &[seeds],
+        ),
+        input.amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    d.set_claimed(input.index);
+    d.claimed_amount = d.claimed_amount.saturating_add(input.amount);
+    d.claimed_count = d.claimed_count.saturating_add(1);
+
+    emit!(MappedTokensClaimed {
+        event_seq: reg.next_event_seq(),
+        registry: registry_key,
+        distributor: d.key(),
+        holder,
+        index: input.index,
+        amount: input.amount,
+        claimed_amount: d.claimed_amount,
+    });
+
+    Ok(())
+}
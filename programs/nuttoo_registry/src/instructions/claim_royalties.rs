@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ClaimRoyaltiesInput {
+    /// Amount to claim; `None` claims everything accrued.
+    pub amount: Option<u64>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRoyalties<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(has_one = registry, has_one = owner)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        has_one = module,
+        seeds = [ROYALTY_SEED, module.key().as_ref()],
+        bump = royalty_balance.bump
+    )]
+    pub royalty_balance: Account<'info, RoyaltyBalance>,
+
+    /// CHECK: any account may receive lamports.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<ClaimRoyalties>, input: ClaimRoyaltiesInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let rb = &mut ctx.accounts.royalty_balance;
+
+    let claimable = rb.claimable();
+    let amount = input.amount.unwrap_or(claimable);
+    if amount == 0 || amount > claimable {
+        return err!(NuttooError::InvalidInput);
+    }
+
+    let info = rb.to_account_info();
+    **info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.recipient.try_borrow_mut_lamports()? += amount;
+    rb.claimed = rb.claimed.saturating_add(amount);
+
+    emit!(RoyaltiesClaimed {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: rb.module,
+        royalty_balance: rb.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        claimed: rb.claimed,
+    });
+
+    Ok(())
+}
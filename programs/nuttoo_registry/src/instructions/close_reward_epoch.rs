@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct CloseRewardEpoch<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        close = authority,
+        seeds = [EPOCH_SEED, registry.key().as_ref(), &reward_epoch.index.to_le_bytes()],
+        bump = reward_epoch.bump
+    )]
+    pub reward_epoch: Account<'info, RewardEpoch>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Returns whatever the pool still holds (unclaimed shares, rounding dust,
+/// or the whole pool when nothing accrued) to the authority.
+pub fn handle(ctx: Context<CloseRewardEpoch>) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    let e = &ctx.accounts.reward_epoch;
+    let now = Clock::get()?.unix_timestamp;
+    if !e.can_sweep(now) {
+        return err!(NuttooError::EpochOpen);
+    }
+
+    if reg.active_epoch == e.key() {
+        reg.active_epoch = Pubkey::default();
+    }
+    reg.touch(now);
+
+    emit!(RewardEpochClosed {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        reward_epoch: e.key(),
+        index: e.index,
+        claimed_amount: e.claimed_amount,
+        swept: e.to_account_info().lamports(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CommitRunBatchInput {
+    pub batch_id: [u8; 32],
+    pub merkle_root: [u8; 32],
+    pub count: u32,
+    pub first_run_at: i64,
+    pub last_run_at: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(input: CommitRunBatchInput)]
+pub struct CommitRunBatch<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(mut, has_one = registry)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = RunBatch::LEN,
+        seeds = [RUN_BATCH_SEED, registry.key().as_ref(), &input.batch_id],
+        bump
+    )]
+    pub run_batch: Account<'info, RunBatch>,
+
+    /// Registered worker that executed the runs; required while `policy.limits_workers()`.
+    #[account(mut, has_one = registry)]
+    pub worker: Option<Account<'info, Worker>>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        seeds = [TREASURY_SEED, registry.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub fee_tokens: FeeTokenAccounts<'info>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, CommitRunBatch<'info>>, input: CommitRunBatchInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    let now = Clock::get()?.unix_timestamp;
+
+    if input.count == 0 || input.merkle_root == [0u8; 32] {
+        return err!(NuttooError::InvalidInput);
+    }
+    if input.first_run_at <= 0
+        || input.last_run_at < input.first_run_at
+        || input.last_run_at > now.saturating_add(MAX_RUN_CLOCK_SKEW_SECS)
+    {
+        return err!(NuttooError::InvalidInput);
+    }
+
+    let m = &mut ctx.accounts.module;
+    m.require_runnable()?;
+    m.consume_run_quota_n(&reg.policy, now, input.count)?;
+    Worker::consume_runs(ctx.accounts.worker.as_deref_mut(), &reg.policy, now, input.count)?;
+
+    let fee_lamports = reg.policy.run_fee_lamports.saturating_mul(input.count as u64);
+    collect_run_fee(
+        reg,
+        m,
+        ctx.remaining_accounts,
+        &mut ctx.accounts.treasury,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.fee_tokens,
+        fee_lamports,
+    )?;
+
+    let b = &mut ctx.accounts.run_batch;
+    b.registry = reg.key();
+    b.module = m.key();
+    b.batch_id = input.batch_id;
+    b.merkle_root = input.merkle_root;
+    b.count = input.count;
+    b.first_run_at = input.first_run_at;
+    b.last_run_at = input.last_run_at;
+    b.committed_at = now;
+    b.committer = ctx.accounts.authority.key();
+    b.bump = ctx.bumps.run_batch;
+
+    m.run_count = m.run_count.saturating_add(input.count as u64);
+    m.updated_at = now;
+
+    reg.run_count = reg.run_count.saturating_add(input.count as u64);
+    reg.touch(now);
+
+    emit!(RunBatchCommitted {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: m.key(),
+        run_batch: b.key(),
+        batch_id: b.batch_id,
+        merkle_root: b.merkle_root,
+        count: b.count,
+        first_run_at: b.first_run_at,
+        last_run_at: b.last_run_at,
+        fee_lamports,
+        fee_mint: reg.policy.fee_mint,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateCategoryInput {
+    pub slug: String,
+    pub description: String,
+}
+
+#[derive(Accounts)]
+#[instruction(input: CreateCategoryInput)]
+pub struct CreateCategory<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Category::space_for(input.slug.len(), input.description.len()),
+        seeds = [CATEGORY_SEED, registry.key().as_ref(), input.slug.as_bytes()],
+        bump
+    )]
+    pub category: Account<'info, Category>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<CreateCategory>, input: CreateCategoryInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    validate_slug(&input.slug, MAX_CATEGORY_SLUG_LEN)?;
+    require_max_len(&input.description, MAX_DESC_LEN)?;
+
+    let c = &mut ctx.accounts.category;
+    c.registry = reg.key();
+    c.slug = input.slug;
+    c.description = input.description;
+    c.created_at = Clock::get()?.unix_timestamp;
+    c.bump = ctx.bumps.category;
+
+    emit!(CategoryCreated {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        category: c.key(),
+        slug: c.slug.clone(),
+    });
+
+    Ok(())
+}
@@ -9,6 +9,8 @@ use crate::utils::*;
 pub struct CreateForkInput {
     pub fork_id: [u8; 32],
     pub notes: String,
+    /// Explicitly pin the module's current version, allowing forks of a yanked version.
+    pub pinned_version: Option<String>,
 }
 
 #[derive(Accounts)]
@@ -17,6 +19,7 @@ pub struct CreateFork<'info> {
     #[account(mut)]
     pub registry: Account<'info, Registry>,
 
+    #[account(mut, has_one = registry)]
     pub module: Account<'info, Module>,
 
     #[account(
@@ -28,6 +31,33 @@ pub struct CreateFork<'info> {
     )]
     pub fork: Account<'info, Fork>,
 
+    /// CHECK: per-version state of the module's current version; may be uninitialized.
+    #[account(seeds = [VERSION_SEED, module.key().as_ref(), module.meta.version.as_bytes()], bump)]
+    pub module_version: UncheckedAccount<'info>,
+
+    /// Registry's active reward epoch; required while one is set and the fork earns points.
+    #[account(mut, address = registry.active_epoch)]
+    pub reward_epoch: Option<Account<'info, RewardEpoch>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ModuleUsage::LEN,
+        seeds = [USAGE_SEED, registry.active_epoch.as_ref(), module.key().as_ref()],
+        bump
+    )]
+    pub module_usage: Option<Account<'info, ModuleUsage>>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        seeds = [TREASURY_SEED, registry.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub fee_tokens: FeeTokenAccounts<'info>,
+
     pub authority: Signer<'info>,
 
     #[account(mut)]
@@ -43,28 +73,77 @@ pub fn handle(ctx: Context<CreateFork>, input: CreateForkInput) -> Result<()> {
         return err!(NuttooError::PolicyViolation);
     }
 
+    ctx.accounts.module.require_runnable()?;
+
     validate_notes(&input.notes)?;
 
+    let pinned = input.pinned_version.as_deref() == Some(ctx.accounts.module.meta.version.as_str());
+    if let Some(v) = ModuleVersion::load_optional(&ctx.accounts.module_version)? {
+        if !v.is_resolvable(pinned) {
+            return err!(NuttooError::VersionYanked);
+        }
+    }
+
+    let m = &mut ctx.accounts.module;
+    let owner = ctx.accounts.authority.key();
+    if reg.policy.derived_ids && input.fork_id != derive_fork_id(&m.key(), &owner, m.fork_count) {
+        return err!(NuttooError::DerivedIdMismatch);
+    }
+
+    let fee_lamports = reg.policy.fork_fee_lamports;
+    collect_fee(
+        &reg.policy,
+        &mut ctx.accounts.treasury,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.fee_tokens,
+        fee_lamports,
+    )?;
+
     let now = Clock::get()?.unix_timestamp;
 
     let f = &mut ctx.accounts.fork;
     f.registry = reg.key();
     f.fork_id = input.fork_id;
-    f.module = ctx.accounts.module.key();
-    f.owner = ctx.accounts.authority.key();
+    f.module = m.key();
+    f.owner = owner;
     f.created_at = now;
+    f.version = m.meta.version.clone();
     f.notes = input.notes;
+    f.promoted_to = Pubkey::default();
     f.bump = ctx.bumps.fork;
 
+    m.fork_count = m.fork_count.saturating_add(1);
+    m.refresh_reputation(&reg.policy.reputation, now);
+
+    // Free forks and forks of one's own module cost nothing to repeat, so they earn no points.
+    if fee_lamports > 0 && owner != m.owner {
+        accrue_usage(
+            &mut reg.active_epoch,
+            ctx.accounts.reward_epoch.as_mut(),
+            ctx.accounts.module_usage.as_mut(),
+            ctx.bumps.module_usage.unwrap_or_default(),
+            m.key(),
+            UsageKind::Fork,
+            now,
+        )?;
+    }
+
     reg.fork_count = reg.fork_count.saturating_add(1);
     reg.touch(now);
 
     emit!(ForkCreated {
+        event_seq: reg.next_event_seq(),
         registry: reg.key(),
         fork: f.key(),
         fork_id: f.fork_id,
         module: f.module,
         owner: f.owner,
+        version: f.version.clone(),
+        module_fork_count: m.fork_count,
+        fee_lamports,
+        fee_mint: reg.policy.fee_mint,
+        created_at: f.created_at,
     });
 
     Ok(())
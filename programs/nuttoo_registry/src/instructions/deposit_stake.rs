@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DepositStakeInput {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct DepositStake<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        init_if_needed,
+        payer = publisher,
+        space = Stake::LEN,
+        seeds = [STAKE_SEED, registry.key().as_ref(), publisher.key().as_ref()],
+        bump
+    )]
+    pub stake: Account<'info, Stake>,
+
+    #[account(mut)]
+    pub publisher: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<DepositStake>, input: DepositStakeInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+
+    if input.amount == 0 {
+        return err!(NuttooError::InvalidInput);
+    }
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.publisher.to_account_info(),
+                to: ctx.accounts.stake.to_account_info(),
+            },
+        ),
+        input.amount,
+    )?;
+
+    let s = &mut ctx.accounts.stake;
+    if s.registry == Pubkey::default() {
+        s.registry = reg.key();
+        s.publisher = ctx.accounts.publisher.key();
+        s.bump = ctx.bumps.stake;
+    }
+    s.amount = s.amount.saturating_add(input.amount);
+
+    emit!(StakeDeposited {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        stake: s.key(),
+        publisher: s.publisher,
+        amount: input.amount,
+        total: s.amount,
+    });
+
+    Ok(())
+}
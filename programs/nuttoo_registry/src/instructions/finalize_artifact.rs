@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct FinalizeArtifact<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(has_one = registry)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        mut,
+        seeds = [ARTIFACT_BUFFER_SEED, registry.key().as_ref(), module.key().as_ref()],
+        bump
+    )]
+    pub artifact_buffer: AccountLoader<'info, ArtifactBuffer>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<FinalizeArtifact>) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    let m = &ctx.accounts.module;
+
+    ctx.accounts.artifact_buffer.load()?;
+    let info = ctx.accounts.artifact_buffer.to_account_info();
+    let mut data = info.try_borrow_mut_data()?;
+    let (buf, bytes) = ArtifactBuffer::split_mut(&mut data)?;
+
+    if buf.finalized != 0 {
+        return err!(NuttooError::ArtifactFinalized);
+    }
+    if buf.size as u64 != m.artifact_size {
+        return err!(NuttooError::ArtifactHashMismatch);
+    }
+    if sha256_32(bytes) != m.artifact_sha256 {
+        return err!(NuttooError::ArtifactHashMismatch);
+    }
+
+    buf.finalized = 1;
+    buf.finalized_at = Clock::get()?.unix_timestamp;
+
+    emit!(ArtifactFinalized {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: m.key(),
+        artifact_buffer: info.key(),
+        artifact_sha256: m.artifact_sha256,
+        size: buf.size,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct InitArtifactBuffer<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(has_one = registry)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ArtifactBuffer::space_for(module.artifact_size.min(MAX_ONCHAIN_ARTIFACT_BYTES)),
+        seeds = [ARTIFACT_BUFFER_SEED, registry.key().as_ref(), module.key().as_ref()],
+        bump
+    )]
+    pub artifact_buffer: AccountLoader<'info, ArtifactBuffer>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<InitArtifactBuffer>) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    let m = &ctx.accounts.module;
+    if m.artifact_size == 0 {
+        return err!(NuttooError::InvalidInput);
+    }
+    if m.artifact_size > MAX_ONCHAIN_ARTIFACT_BYTES || m.artifact_size > reg.policy.max_artifact_bytes {
+        return err!(NuttooError::ArtifactTooLarge);
+    }
+
+    let mut buf = ctx.accounts.artifact_buffer.load_init()?;
+    buf.registry = reg.key();
+    buf.module = m.key();
+    buf.size = m.artifact_size as u32;
+    buf.finalized = 0;
+    buf.finalized_at = 0;
+    buf.bump = ctx.bumps.artifact_buffer;
+
+    emit!(ArtifactBufferInitialized {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: m.key(),
+        artifact_buffer: ctx.accounts.artifact_buffer.key(),
+        size: buf.size,
+    });
+
+    Ok(())
+}
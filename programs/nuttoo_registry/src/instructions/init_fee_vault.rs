@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct InitFeeVault<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        has_one = registry,
+        seeds = [TREASURY_SEED, registry.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mint::token_program = token_program)]
+    pub fee_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = fee_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<InitFeeVault>) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    if reg.policy.fee_mint != Some(ctx.accounts.fee_mint.key()) {
+        return err!(NuttooError::InvalidFeeAccounts);
+    }
+
+    emit!(FeeVaultInitialized {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        treasury: ctx.accounts.treasury.key(),
+        fee_mint: ctx.accounts.fee_mint.key(),
+        fee_vault: ctx.accounts.fee_vault.key(),
+        token_program: ctx.accounts.token_program.key(),
+    });
+
+    Ok(())
+}
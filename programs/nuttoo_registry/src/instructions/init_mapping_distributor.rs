@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitMappingDistributorInput {
+    pub merkle_root: [u8; 32],
+    pub snapshot_slot: u64,
+    pub num_leaves: u32,
+    pub total_amount: u64,
+    pub denylist: Vec<Pubkey>,
+}
+
+#[derive(Accounts)]
+#[instruction(input: InitMappingDistributorInput)]
+pub struct InitMappingDistributor<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = MappingDistributor::space_for(input.num_leaves),
+        seeds = [MAPPING_SEED, registry.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub distributor: Account<'info, MappingDistributor>,
+
+    /// Production token being distributed.
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = distributor,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<InitMappingDistributor>, input: InitMappingDistributorInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    if input.merkle_root == [0u8; 32]
+        || input.num_leaves == 0
+        || input.num_leaves > MAX_MAPPING_LEAVES
+        || input.total_amount == 0
+        || input.denylist.len() > MAX_MAPPING_DENYLIST
+    {
+        return err!(NuttooError::InvalidInput);
+    }
+
+    let d = &mut ctx.accounts.distributor;
+    d.registry = reg.key();
+    d.mint = ctx.accounts.mint.key();
+    d.merkle_root = input.merkle_root;
+    d.snapshot_slot = input.snapshot_slot;
+    d.num_leaves = input.num_leaves;
+    d.total_amount = input.total_amount;
+    d.claimed_amount = 0;
+    d.claimed_count = 0;
+    d.created_at = Clock::get()?.unix_timestamp;
+    d.denylist = input.denylist;
+    d.claimed_bitmap = vec![0u8; MappingDistributor::bitmap_len(input.num_leaves)];
+    d.bump = ctx.bumps.distributor;
+
+    emit!(MappingDistributorInitialized {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        distributor: d.key(),
+        mint: d.mint,
+        vault: ctx.accounts.vault.key(),
+        merkle_root: d.merkle_root,
+        snapshot_slot: d.snapshot_slot,
+        num_leaves: d.num_leaves,
+        total_amount: d.total_amount,
+        denylist: d.denylist.clone(),
+    });
+
+    Ok(())
+}
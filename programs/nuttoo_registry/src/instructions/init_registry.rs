@@ -21,6 +21,15 @@ pub struct InitRegistry<'info> {
     )]
     pub registry: Account<'info, Registry>,
 
+    #[account(
+        init,
+        payer = payer,
+        space = Treasury::LEN,
+        seeds = [TREASURY_SEED, registry.key().as_ref()],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -28,6 +37,8 @@ pub struct InitRegistry<'info> {
 }
 
 pub fn handle(ctx: Context<InitRegistry>, input: InitRegistryInput) -> Result<()> {
+    input.policy.validate()?;
+
     let now = Clock::get()?.unix_timestamp;
 
     let reg = &mut ctx.accounts.registry;
@@ -38,11 +49,22 @@ pub fn handle(ctx: Context<InitRegistry>, input: InitRegistryInput) -> Result<()
     reg.module_count = 0;
     reg.fork_count = 0;
     reg.run_count = 0;
+    reg.event_seq = 0;
+    reg.epoch_count = 0;
+    reg.active_epoch = Pubkey::default();
     reg.bump = ctx.bumps.registry;
 
+    let t = &mut ctx.accounts.treasury;
+    t.registry = reg.key();
+    t.total_collected = 0;
+    t.total_withdrawn = 0;
+    t.bump = ctx.bumps.treasury;
+
     emit!(RegistryInitialized {
+        event_seq: reg.next_event_seq(),
         authority: reg.authority,
         registry: reg.key(),
+        policy: reg.policy,
     });
 
     Ok(())
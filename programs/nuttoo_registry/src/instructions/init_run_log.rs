@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitRunLogInput {
+    pub capacity: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(input: InitRunLogInput)]
+pub struct InitRunLog<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(has_one = registry)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = RunLog::space_for(input.capacity),
+        seeds = [RUN_LOG_SEED, registry.key().as_ref(), module.key().as_ref()],
+        bump
+    )]
+    pub run_log: AccountLoader<'info, RunLog>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<InitRunLog>, input: InitRunLogInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    if input.capacity == 0 || input.capacity > MAX_RUN_LOG_CAPACITY {
+        return err!(NuttooError::InvalidInput);
+    }
+
+    let mut log = ctx.accounts.run_log.load_init()?;
+    log.registry = reg.key();
+    log.module = ctx.accounts.module.key();
+    log.capacity = input.capacity;
+    log.head = 0;
+    log.len = 0;
+    log.total = 0;
+    log.bump = ctx.bumps.run_log;
+
+    emit!(RunLogInitialized {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: log.module,
+        run_log: ctx.accounts.run_log.key(),
+        capacity: log.capacity,
+    });
+
+    Ok(())
+}
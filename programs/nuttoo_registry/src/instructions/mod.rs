@@ -5,6 +5,50 @@ pub mod create_fork;
 pub mod attest_artifact;
 pub mod record_run;
 pub mod set_policy;
+pub mod add_attester;
+pub mod remove_attester;
+pub mod challenge_run;
+pub mod resolve_dispute;
+pub mod set_module_status;
+pub mod register_worker;
+pub mod set_worker_status;
+pub mod init_run_log;
+pub mod record_runs_batch;
+pub mod commit_run_batch;
+pub mod verify_run_inclusion;
+pub mod init_artifact_buffer;
+pub mod write_artifact_chunk;
+pub mod finalize_artifact;
+pub mod add_artifact;
+pub mod create_category;
+pub mod set_module_tags;
+pub mod rename_module;
+pub mod yank_version;
+pub mod unyank_version;
+pub mod withdraw_treasury;
+pub mod init_fee_vault;
+pub mod withdraw_treasury_tokens;
+pub mod set_module_royalty;
+pub mod claim_royalties;
+pub mod post_bounty;
+pub mod claim_bounty;
+pub mod cancel_bounty;
+pub mod deposit_stake;
+pub mod request_unstake;
+pub mod withdraw_stake;
+pub mod slash_publisher;
+pub mod init_mapping_distributor;
+pub mod set_mapping_denylist;
+pub mod claim_mapped_tokens;
+pub mod open_reward_epoch;
+pub mod claim_epoch_reward;
+pub mod close_reward_epoch;
+pub mod sponsor_module;
+pub mod release_sponsorship;
+pub mod cancel_sponsorship;
+pub mod withdraw_sponsorship;
+pub mod recompute_reputation;
+pub mod submit_review;
 
 pub use init_registry::*;
 pub use publish_module::*;
@@ -13,3 +57,47 @@ pub use create_fork::*;
 pub use attest_artifact::*;
 pub use record_run::*;
 pub use set_policy::*;
+pub use add_attester::*;
+pub use remove_attester::*;
+pub use challenge_run::*;
+pub use resolve_dispute::*;
+pub use set_module_status::*;
+pub use register_worker::*;
+pub use set_worker_status::*;
+pub use init_run_log::*;
+pub use record_runs_batch::*;
+pub use commit_run_batch::*;
+pub use verify_run_inclusion::*;
+pub use init_artifact_buffer::*;
+pub use write_artifact_chunk::*;
+pub use finalize_artifact::*;
+pub use add_artifact::*;
+pub use create_category::*;
+pub use set_module_tags::*;
+pub use rename_module::*;
+pub use yank_version::*;
+pub use unyank_version::*;
+pub use withdraw_treasury::*;
+pub use init_fee_vault::*;
+pub use withdraw_treasury_tokens::*;
+pub use set_module_royalty::*;
+pub use claim_royalties::*;
+pub use post_bounty::*;
+pub use claim_bounty::*;
+pub use cancel_bounty::*;
+pub use deposit_stake::*;
+pub use request_unstake::*;
+pub use withdraw_stake::*;
+pub use slash_publisher::*;
+pub use init_mapping_distributor::*;
+pub use set_mapping_denylist::*;
+pub use claim_mapped_tokens::*;
+pub use open_reward_epoch::*;
+pub use claim_epoch_reward::*;
+pub use close_reward_epoch::*;
+pub use sponsor_module::*;
+pub use release_sponsorship::*;
+pub use cancel_sponsorship::*;
+pub use withdraw_sponsorship::*;
+pub use recompute_reputation::*;
+pub use submit_review::*;
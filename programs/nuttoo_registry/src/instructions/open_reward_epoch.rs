@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OpenRewardEpochInput {
+    pub duration_secs: u32,
+    /// Lamports moved from the authority into the epoch's pool.
+    pub pool: u64,
+    pub run_points: u32,
+    pub fork_points: u32,
+    /// Seconds after the epoch ends during which rewards may be claimed.
+    pub claim_window_secs: u32,
+}
+
+#[derive(Accounts)]
+pub struct OpenRewardEpoch<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RewardEpoch::LEN,
+        seeds = [EPOCH_SEED, registry.key().as_ref(), &registry.epoch_count.to_le_bytes()],
+        bump
+    )]
+    pub reward_epoch: Account<'info, RewardEpoch>,
+
+    /// The currently active epoch, which must have ended; omitted for the first epoch.
+    #[account(address = registry.active_epoch)]
+    pub previous_epoch: Option<Account<'info, RewardEpoch>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<OpenRewardEpoch>, input: OpenRewardEpochInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    if input.duration_secs == 0 || input.claim_window_secs == 0 || input.pool == 0 || (input.run_points == 0 && input.fork_points == 0) {
+        return err!(NuttooError::InvalidInput);
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if reg.active_epoch != Pubkey::default() {
+        let previous = ctx.accounts.previous_epoch.as_ref().ok_or(NuttooError::InvalidInput)?;
+        if now < previous.end_at {
+            return err!(NuttooError::EpochOpen);
+        }
+    }
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.reward_epoch.to_account_info(),
+            },
+        ),
+        input.pool,
+    )?;
+
+    let e = &mut ctx.accounts.reward_epoch;
+    e.registry = reg.key();
+    e.index = reg.epoch_count;
+    e.start_at = now;
+    e.end_at = now.saturating_add(input.duration_secs as i64);
+    e.pool = input.pool;
+    e.run_points = input.run_points;
+    e.fork_points = input.fork_points;
+    e.total_points = 0;
+    e.claimed_amount = 0;
+    e.claim_deadline = e.end_at.saturating_add(input.claim_window_secs as i64);
+    e.bump = ctx.bumps.reward_epoch;
+
+    reg.epoch_count = reg.epoch_count.saturating_add(1);
+    reg.active_epoch = e.key();
+    reg.touch(now);
+
+    emit!(RewardEpochOpened {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        reward_epoch: e.key(),
+        index: e.index,
+        start_at: e.start_at,
+        end_at: e.end_at,
+        pool: e.pool,
+        run_points: e.run_points,
+        fork_points: e.fork_points,
+        claim_deadline: e.claim_deadline,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PostBountyInput {
+    pub bounty_id: [u8; 32],
+    pub version: String,
+    pub inputs_sha256: [u8; 32],
+    pub reward: u64,
+    pub expires_at: i64,
+    /// Allow a bounty on a yanked version.
+    pub pinned: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(input: PostBountyInput)]
+pub struct PostBounty<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(has_one = registry)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        init,
+        payer = poster,
+        space = Bounty::LEN,
+        seeds = [BOUNTY_SEED, registry.key().as_ref(), &input.bounty_id],
+        bump
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: per-version state of `input.version`; may be uninitialized.
+    #[account(seeds = [VERSION_SEED, module.key().as_ref(), input.version.as_bytes()], bump)]
+    pub module_version: UncheckedAccount<'info>,
+
+    /// Present for token rewards; `vault` is the bounty's associated token account.
+    pub escrow_tokens: EscrowTokenAccounts<'info>,
+
+    #[account(mut)]
+    pub poster: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<PostBounty>, input: PostBountyInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let m = &ctx.accounts.module;
+
+    require_nonempty(&input.version)?;
+    require_max_len(&input.version, MAX_VERSION_LEN)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    if input.reward == 0 || input.expires_at <= now {
+        return err!(NuttooError::InvalidInput);
+    }
+    m.require_runnable()?;
+    if let Some(v) = ModuleVersion::load_optional(&ctx.accounts.module_version)? {
+        if !v.is_resolvable(input.pinned) {
+            return err!(NuttooError::VersionYanked);
+        }
+    }
+
+    let mint = ctx.accounts.escrow_tokens.mint_key();
+    let received = escrow_deposit(
+        &ctx.accounts.bounty.to_account_info(),
+        mint,
+        &ctx.accounts.escrow_tokens,
+        &ctx.accounts.poster.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        input.reward,
+    )?;
+
+    let b = &mut ctx.accounts.bounty;
+    b.registry = reg.key();
+    b.bounty_id = input.bounty_id;
+    b.poster = ctx.accounts.poster.key();
+    b.module = m.key();
+    b.version = input.version;
+    b.inputs_sha256 = input.inputs_sha256;
+    b.mint = mint;
+    // Token-2022 transfer fees can leave the vault short of `input.reward`.
+    b.reward = received;
+    b.created_at = now;
+    b.expires_at = input.expires_at;
+    b.bump = ctx.bumps.bounty;
+
+    emit!(BountyPosted {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        bounty: b.key(),
+        bounty_id: b.bounty_id,
+        poster: b.poster,
+        module: b.module,
+        version: b.version.clone(),
+        inputs_sha256: b.inputs_sha256,
+        mint: b.mint,
+        reward: b.reward,
+        expires_at: b.expires_at,
+    });
+
+    Ok(())
+}
@@ -4,6 +4,7 @@ use crate::constants::*;
 use crate::errors::NuttooError;
 use crate::events::*;
 use crate::utils::*;
+use crate::manifest::Manifest;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct PublishModuleInput {
@@ -13,9 +14,13 @@ pub struct PublishModuleInput {
     pub description: String,
     pub uri: String,
     pub manifest_sha256: [u8; 32],
+    /// Canonical manifest bytes; when present the program hashes them itself.
+    pub manifest: Option<Vec<u8>>,
     pub artifact_sha256: [u8; 32],
     pub artifact_size: u64,
     pub verified: bool,
+    pub tags: Vec<String>,
+    pub royalty_bps: u16,
 }
 
 #[derive(Accounts)]
@@ -38,8 +43,53 @@ pub struct PublishModule<'info> {
     )]
     pub module: Account<'info, Module>,
 
+    #[account(
+        init,
+        payer = payer,
+        space = NameRecord::LEN,
+        seeds = [NAME_SEED, registry.key().as_ref(), &name_hash(&input.name)],
+        bump
+    )]
+    pub name_record: Account<'info, NameRecord>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = RoyaltyBalance::LEN,
+        seeds = [ROYALTY_SEED, module.key().as_ref()],
+        bump
+    )]
+    pub royalty_balance: Account<'info, RoyaltyBalance>,
+
+    /// Fork being promoted to a module; links the new module to `parent_module`.
+    /// Must be owned by `publisher` and not promoted before.
+    #[account(mut, has_one = registry)]
+    pub source_fork: Option<Account<'info, Fork>>,
+
+    #[account(has_one = registry)]
+    pub parent_module: Option<Account<'info, Module>>,
+
+    /// CHECK: publisher stake; must be initialized when `Policy::min_publish_stake` is
+    /// non-zero, and is always passed so an existing stake counts the new module.
+    #[account(mut, seeds = [STAKE_SEED, registry.key().as_ref(), publisher.key().as_ref()], bump)]
+    pub stake: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        seeds = [TREASURY_SEED, registry.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub fee_tokens: FeeTokenAccounts<'info>,
+
     pub authority: Signer<'info>,
 
+    /// Owner of the new module, co-signing with the registry authority; its
+    /// stake backs the module. May be the authority itself.
+    pub publisher: Signer<'info>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -52,6 +102,55 @@ pub fn handle(ctx: Context<PublishModule>, input: PublishModuleInput) -> Result<
     require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
 
     validate_module_meta(&input.name, &input.version, &input.description, &input.uri)?;
+    validate_module_name(&input.name)?;
+
+    if reg.policy.derived_ids && input.module_id != derive_module_id(&ctx.accounts.publisher.key(), &input.name) {
+        return err!(NuttooError::DerivedIdMismatch);
+    }
+    validate_tags(&input.tags)?;
+
+    let stake_info = ctx.accounts.stake.to_account_info();
+    match Stake::load_optional(&stake_info)? {
+        Some(mut s) => {
+            if !s.covers(&reg.policy) {
+                return err!(NuttooError::InsufficientStake);
+            }
+            s.live_modules = s.live_modules.saturating_add(1);
+            s.store(&stake_info)?;
+        }
+        None if reg.policy.min_publish_stake > 0 => return err!(NuttooError::InsufficientStake),
+        None => {}
+    }
+
+    if input.royalty_bps > reg.policy.max_royalty_bps {
+        return err!(NuttooError::PolicyViolation);
+    }
+
+    let module_key = ctx.accounts.module.key();
+    let publisher = ctx.accounts.publisher.key();
+    let (parent, source_fork, lineage_depth) = match (ctx.accounts.source_fork.as_mut(), &ctx.accounts.parent_module) {
+        (Some(f), Some(p)) => {
+            require_keys_eq!(f.module, p.key(), NuttooError::InvalidLineage);
+            require_keys_eq!(f.owner, publisher, NuttooError::Unauthorized);
+            if f.promoted_to != Pubkey::default() {
+                return err!(NuttooError::ForkAlreadyPromoted);
+            }
+            f.promoted_to = module_key;
+            (p.key(), f.key(), p.lineage_depth.saturating_add(1))
+        }
+        (None, None) => (Pubkey::default(), Pubkey::default(), 0),
+        _ => return err!(NuttooError::InvalidLineage),
+    };
+
+    if let Some(bytes) = input.manifest.as_ref() {
+        let manifest = Manifest::decode_canonical(bytes)?;
+        if manifest.name != input.name || manifest.version != input.version {
+            return err!(NuttooError::InvalidInput);
+        }
+        if sha256_32(bytes) != input.manifest_sha256 {
+            return err!(NuttooError::ManifestHashMismatch);
+        }
+    }
 
     if input.artifact_size > reg.policy.max_artifact_bytes {
         return err!(NuttooError::ArtifactTooLarge);
@@ -61,6 +160,18 @@ pub fn handle(ctx: Context<PublishModule>, input: PublishModuleInput) -> Result<
         return err!(NuttooError::PolicyViolation);
     }
 
+    check_content_addressed_uri(&reg.policy, &input.uri, &input.artifact_sha256)?;
+
+    let fee_lamports = reg.policy.publish_fee_lamports;
+    collect_fee(
+        &reg.policy,
+        &mut ctx.accounts.treasury,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.fee_tokens,
+        fee_lamports,
+    )?;
+
     let now = Clock::get()?.unix_timestamp;
 
     let meta = ModuleMeta {
@@ -73,7 +184,7 @@ pub fn handle(ctx: Context<PublishModule>, input: PublishModuleInput) -> Result<
     let m = &mut ctx.accounts.module;
     m.registry = reg.key();
     m.module_id = input.module_id;
-    m.owner = ctx.accounts.authority.key();
+    m.owner = publisher;
     m.created_at = now;
     m.updated_at = now;
     m.meta = meta;
@@ -82,17 +193,72 @@ pub fn handle(ctx: Context<PublishModule>, input: PublishModuleInput) -> Result<
     m.artifact_size = input.artifact_size;
     m.verified = input.verified;
     m.run_count = 0;
+    m.fork_count = 0;
+    m.status = ModuleStatus::Active;
+    m.run_stats = RunStats::default();
+    m.run_window = RateWindow::default();
+    m.tags = input.tags;
+    m.category = Pubkey::default();
+    m.parent = parent;
+    m.source_fork = source_fork;
+    m.lineage_depth = lineage_depth;
+    m.royalty_bps = input.royalty_bps;
+    m.attestation_count = m.verified as u32;
+    m.reputation = 0;
+    m.review_count = 0;
+    m.rating_total = 0;
+    m.broken_reports = 0;
+    m.insecure_reports = 0;
+    m.refresh_reputation(&reg.policy.reputation, now);
     m.bump = ctx.bumps.module;
 
+    let rb = &mut ctx.accounts.royalty_balance;
+    rb.registry = reg.key();
+    rb.module = m.key();
+    rb.accrued = 0;
+    rb.claimed = 0;
+    rb.bump = ctx.bumps.royalty_balance;
+
+    let n = &mut ctx.accounts.name_record;
+    n.registry = reg.key();
+    n.module = m.key();
+    n.name = normalize_module_name(&m.meta.name);
+    n.bump = ctx.bumps.name_record;
+
     reg.module_count = reg.module_count.saturating_add(1);
     reg.touch(now);
 
     emit!(ModulePublished {
+        event_seq: reg.next_event_seq(),
         registry: reg.key(),
         module: m.key(),
         module_id: m.module_id,
         owner: m.owner,
+        meta: m.meta.clone(),
+        manifest_sha256: m.manifest_sha256,
+        artifact_sha256: m.artifact_sha256,
+        artifact_size: m.artifact_size,
+        verified: m.verified,
+        parent: m.parent,
+        source_fork: m.source_fork,
+        royalty_bps: m.royalty_bps,
+        fee_lamports,
+        fee_mint: reg.policy.fee_mint,
+        created_at: m.created_at,
     });
 
+    if !m.tags.is_empty() {
+        emit!(ModuleTagged {
+            event_seq: reg.next_event_seq(),
+            registry: reg.key(),
+            module: m.key(),
+            module_id: m.module_id,
+            tags: m.tags.clone(),
+            category: m.category,
+            previous_tags: Vec::new(),
+            previous_category: Pubkey::default(),
+        });
+    }
+
     Ok(())
 }
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct RecomputeReputation<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(mut, has_one = registry)]
+    pub module: Account<'info, Module>,
+}
+
+/// Permissionless crank: re-scores a module under the current policy weights,
+/// e.g. after `set_policy` or to fold in the module's age.
+pub fn handle(ctx: Context<RecomputeReputation>) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let m = &mut ctx.accounts.module;
+
+    let now = Clock::get()?.unix_timestamp;
+    let previous_reputation = m.refresh_reputation(&reg.policy.reputation, now);
+
+    emit!(ReputationUpdated {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: m.key(),
+        module_id: m.module_id,
+        reputation: m.reputation,
+        previous_reputation,
+        updated_at: now,
+    });
+
+    Ok(())
+}
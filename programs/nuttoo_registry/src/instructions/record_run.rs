@@ -3,6 +3,7 @@ use crate::state::*;
 use crate::constants::*;
 use crate::errors::NuttooError;
 use crate::events::*;
+use crate::utils::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct RecordRunInput {
@@ -12,6 +13,14 @@ pub struct RecordRunInput {
     pub exit_code: i32,
     pub stdout_sha256: [u8; 32],
     pub stderr_sha256: [u8; 32],
+    pub inputs_sha256: [u8; 32],
+    pub outputs_sha256: [u8; 32],
+    pub started_at: i64,
+    pub finished_at: i64,
+    pub wall_time_ms: u64,
+    pub peak_memory_bytes: u64,
+    pub runtime_kind: RuntimeKind,
+    pub runtime_version: String,
 }
 
 #[derive(Accounts)]
@@ -32,6 +41,33 @@ pub struct RecordRun<'info> {
     )]
     pub run: Account<'info, Run>,
 
+    /// Registered worker that executed the run; required while `policy.limits_workers()`.
+    #[account(mut, has_one = registry)]
+    pub worker: Option<Account<'info, Worker>>,
+
+    /// Registry's active reward epoch; required while one is set.
+    #[account(mut, address = registry.active_epoch)]
+    pub reward_epoch: Option<Account<'info, RewardEpoch>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ModuleUsage::LEN,
+        seeds = [USAGE_SEED, registry.active_epoch.as_ref(), module.key().as_ref()],
+        bump
+    )]
+    pub module_usage: Option<Account<'info, ModuleUsage>>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        seeds = [TREASURY_SEED, registry.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub fee_tokens: FeeTokenAccounts<'info>,
+
     pub authority: Signer<'info>,
 
     #[account(mut)]
@@ -40,44 +76,136 @@ pub struct RecordRun<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handle(ctx: Context<RecordRun>, input: RecordRunInput) -> Result<()> {
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, RecordRun<'info>>, input: RecordRunInput) -> Result<()> {
     let reg = &mut ctx.accounts.registry;
     require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
 
     let m = &mut ctx.accounts.module;
     require_keys_eq!(m.registry, reg.key(), NuttooError::InvalidInput);
+    m.require_runnable()?;
+
+    let now = Clock::get()?.unix_timestamp;
 
-    if m.run_count >= reg.policy.max_runs_per_module {
-        return err!(NuttooError::RunLimitReached);
+    if reg.policy.derived_ids && input.run_id != derive_run_id(&m.key(), &input.inputs_sha256, m.run_count) {
+        return err!(NuttooError::DerivedIdMismatch);
     }
 
-    let now = Clock::get()?.unix_timestamp;
+    m.consume_run_quota(&reg.policy, now)?;
+
+    let worker_key = Worker::consume_runs(ctx.accounts.worker.as_deref_mut(), &reg.policy, now, 1)?;
+
+    validate_run_metrics(
+        input.started_at,
+        input.finished_at,
+        input.wall_time_ms,
+        input.peak_memory_bytes,
+        &input.runtime_version,
+        now,
+    )?;
+
+    let fee_lamports = reg.policy.run_fee_lamports;
+    collect_run_fee(
+        reg,
+        m,
+        ctx.remaining_accounts,
+        &mut ctx.accounts.treasury,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.fee_tokens,
+        fee_lamports,
+    )?;
 
     let r = &mut ctx.accounts.run;
-    r.registry = reg.key();
-    r.run_id = input.run_id;
-    r.module = m.key();
-    r.fork = input.fork;
-    r.owner = ctx.accounts.authority.key();
-    r.created_at = now;
-    r.ok = input.ok;
-    r.exit_code = input.exit_code;
-    r.stdout_sha256 = input.stdout_sha256;
-    r.stderr_sha256 = input.stderr_sha256;
-    r.bump = ctx.bumps.run;
+    write_run(r, reg.key(), m, ctx.accounts.authority.key(), worker_key, now, &input, ctx.bumps.run);
 
     m.run_count = m.run_count.saturating_add(1);
     m.updated_at = now;
+    let quarantined = m.apply_run(r.ok, r.exit_code, now, &reg.policy);
+
+    accrue_usage(
+        &mut reg.active_epoch,
+        ctx.accounts.reward_epoch.as_mut(),
+        ctx.accounts.module_usage.as_mut(),
+        ctx.bumps.module_usage.unwrap_or_default(),
+        m.key(),
+        UsageKind::Run,
+        now,
+    )?;
 
     reg.run_count = reg.run_count.saturating_add(1);
     reg.touch(now);
 
     emit!(RunRecorded {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
         run: r.key(),
         run_id: r.run_id,
         module: r.module,
+        fork: r.fork,
+        owner: r.owner,
+        worker: r.worker,
+        module_version: r.module_version.clone(),
+        module_run_count: m.run_count,
         ok: r.ok,
+        exit_code: r.exit_code,
+        inputs_sha256: r.inputs_sha256,
+        outputs_sha256: r.outputs_sha256,
+        started_at: r.started_at,
+        finished_at: r.finished_at,
+        wall_time_ms: r.wall_time_ms,
+        peak_memory_bytes: r.peak_memory_bytes,
+        runtime_kind: r.runtime_kind,
+        fee_lamports,
+        fee_mint: reg.policy.fee_mint,
     });
 
+    if quarantined {
+        emit!(ModuleStatusChanged {
+            event_seq: reg.next_event_seq(),
+            registry: reg.key(),
+            module: m.key(),
+            module_id: m.module_id,
+            status: m.status,
+            previous_status: ModuleStatus::Active,
+            consecutive_failures: m.run_stats.consecutive_failures,
+        });
+    }
+
     Ok(())
 }
+
+/// Populates a freshly initialized `Run` from a recorded result.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn write_run(
+    r: &mut Run,
+    registry: Pubkey,
+    module: &Account<Module>,
+    owner: Pubkey,
+    worker: Pubkey,
+    now: i64,
+    input: &RecordRunInput,
+    bump: u8,
+) {
+    r.registry = registry;
+    r.run_id = input.run_id;
+    r.module = module.key();
+    r.fork = input.fork;
+    r.owner = owner;
+    r.worker = worker;
+    r.created_at = now;
+    r.ok = input.ok;
+    r.exit_code = input.exit_code;
+    r.stdout_sha256 = input.stdout_sha256;
+    r.stderr_sha256 = input.stderr_sha256;
+    r.inputs_sha256 = input.inputs_sha256;
+    r.outputs_sha256 = input.outputs_sha256;
+    r.started_at = input.started_at;
+    r.finished_at = input.finished_at;
+    r.wall_time_ms = input.wall_time_ms;
+    r.peak_memory_bytes = input.peak_memory_bytes;
+    r.runtime_kind = input.runtime_kind;
+    r.runtime_version = input.runtime_version.clone();
+    r.module_version = module.meta.version.clone();
+    r.disputed = false;
+    r.bump = bump;
+}
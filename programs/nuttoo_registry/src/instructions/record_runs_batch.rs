@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RunLogEntryInput {
+    pub ok: bool,
+    pub exit_code: i32,
+    pub inputs_sha256: [u8; 32],
+    pub outputs_sha256: [u8; 32],
+    pub started_at: i64,
+    pub finished_at: i64,
+    pub wall_time_ms: u64,
+    pub peak_memory_bytes: u64,
+    pub runtime_kind: RuntimeKind,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RecordRunsBatchInput {
+    pub entries: Vec<RunLogEntryInput>,
+}
+
+#[derive(Accounts)]
+pub struct RecordRunsBatch<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(mut, has_one = registry)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        mut,
+        seeds = [RUN_LOG_SEED, registry.key().as_ref(), module.key().as_ref()],
+        bump
+    )]
+    pub run_log: AccountLoader<'info, RunLog>,
+
+    /// Registered worker that executed the runs; required while `policy.limits_workers()`.
+    #[account(mut, has_one = registry)]
+    pub worker: Option<Account<'info, Worker>>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        seeds = [TREASURY_SEED, registry.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub fee_tokens: FeeTokenAccounts<'info>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, RecordRunsBatch<'info>>, input: RecordRunsBatchInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    if input.entries.is_empty() || input.entries.len() > MAX_RUN_BATCH {
+        return err!(NuttooError::InvalidInput);
+    }
+    ctx.accounts.module.require_runnable()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    Worker::consume_runs(ctx.accounts.worker.as_deref_mut(), &reg.policy, now, input.entries.len() as u32)?;
+
+    let fee_lamports = reg.policy.run_fee_lamports;
+    let total_fee = fee_lamports.saturating_mul(input.entries.len() as u64);
+    collect_run_fee(
+        reg,
+        &ctx.accounts.module,
+        ctx.remaining_accounts,
+        &mut ctx.accounts.treasury,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.fee_tokens,
+        total_fee,
+    )?;
+
+    let m = &mut ctx.accounts.module;
+    let run_log_key = ctx.accounts.run_log.key();
+
+    // Make sure the account is a well-formed RunLog before touching raw data.
+    ctx.accounts.run_log.load()?;
+    let info = ctx.accounts.run_log.to_account_info();
+    let mut data = info.try_borrow_mut_data()?;
+    let (log, slots) = RunLog::split_mut(&mut data)?;
+
+    let mut quarantined = false;
+    for e in input.entries.iter() {
+        validate_run_timing(e.started_at, e.finished_at, e.wall_time_ms, e.peak_memory_bytes, now)?;
+        m.consume_run_quota(&reg.policy, now)?;
+
+        let seq = log.push(slots, RunLogEntry {
+            inputs_sha256: e.inputs_sha256,
+            outputs_sha256: e.outputs_sha256,
+            recorded_at: now,
+            wall_time_ms: e.wall_time_ms,
+            exit_code: e.exit_code,
+            ok: e.ok as u8,
+            runtime_kind: e.runtime_kind as u8,
+            _padding: [0; 2],
+        });
+
+        m.run_count = m.run_count.saturating_add(1);
+        quarantined |= m.apply_run(e.ok, e.exit_code, now, &reg.policy);
+        reg.run_count = reg.run_count.saturating_add(1);
+
+        emit!(RunLogged {
+            event_seq: reg.next_event_seq(),
+            registry: reg.key(),
+            module: m.key(),
+            run_log: run_log_key,
+            seq,
+            ok: e.ok,
+            exit_code: e.exit_code,
+            inputs_sha256: e.inputs_sha256,
+            outputs_sha256: e.outputs_sha256,
+            wall_time_ms: e.wall_time_ms,
+            fee_lamports,
+            fee_mint: reg.policy.fee_mint,
+        });
+    }
+
+    m.updated_at = now;
+    reg.touch(now);
+
+    if quarantined {
+        emit!(ModuleStatusChanged {
+            event_seq: reg.next_event_seq(),
+            registry: reg.key(),
+            module: m.key(),
+            module_id: m.module_id,
+            status: m.status,
+            previous_status: ModuleStatus::Active,
+            consecutive_failures: m.run_stats.consecutive_failures,
+        });
+    }
+
+    Ok(())
+}
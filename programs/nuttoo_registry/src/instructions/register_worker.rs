@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RegisterWorkerInput {
+    pub worker: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(input: RegisterWorkerInput)]
+pub struct RegisterWorker<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Worker::LEN,
+        seeds = [WORKER_SEED, registry.key().as_ref(), input.worker.as_ref()],
+        bump
+    )]
+    pub worker: Account<'info, Worker>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<RegisterWorker>, input: RegisterWorkerInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    let w = &mut ctx.accounts.worker;
+    w.registry = reg.key();
+    w.worker = input.worker;
+    w.registered_at = Clock::get()?.unix_timestamp;
+    w.active = true;
+    w.run_count = 0;
+    w.run_window = RateWindow::default();
+    w.bump = ctx.bumps.worker;
+
+    emit!(WorkerRegistered {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        worker: w.worker,
+    });
+
+    Ok(())
+}
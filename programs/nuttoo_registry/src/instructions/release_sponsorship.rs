@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct ReleaseSponsorship<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        seeds = [SPONSOR_ESCROW_SEED, sponsorship.module.as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, SponsorEscrow>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        seeds = [SPONSORSHIP_SEED, sponsorship.module.as_ref(), sponsorship.sponsor.as_ref()],
+        bump = sponsorship.bump
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+
+    /// Present for token schedules; `counterparty` is the escrow's associated
+    /// token account and `vault` the sponsorship's.
+    pub escrow_tokens: EscrowTokenAccounts<'info>,
+}
+
+/// Permissionless: anyone may release a due installment to the module's escrow.
+pub fn handle(ctx: Context<ReleaseSponsorship>) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let s = &mut ctx.accounts.sponsorship;
+    let e = &mut ctx.accounts.escrow;
+
+    let mut schedule = s.schedule.ok_or(NuttooError::InvalidInput)?;
+    if Clock::get()?.unix_timestamp < schedule.next_due_at {
+        return err!(NuttooError::SponsorshipNotDue);
+    }
+
+    if schedule.mint.is_some() {
+        ctx.accounts.escrow_tokens.require_counterparty_ata(&e.key())?;
+    }
+
+    let available = escrow_available(&s.to_account_info(), schedule.mint, &ctx.accounts.escrow_tokens)?;
+    let seeds: &[&[u8]] = &[SPONSORSHIP_SEED, s.module.as_ref(), s.sponsor.as_ref(), &[s.bump]];
+    let amount = escrow_release(
+        &s.to_account_info(),
+        seeds,
+        schedule.mint,
+        &ctx.accounts.escrow_tokens,
+        &e.to_account_info(),
+        schedule.installment(available),
+    )?;
+
+    schedule.remaining_periods -= 1;
+    schedule.next_due_at = schedule.next_due_at.saturating_add(schedule.period_secs as i64);
+    s.schedule = (schedule.remaining_periods > 0).then_some(schedule);
+    e.credit(schedule.mint, amount);
+    s.credit(schedule.mint, amount);
+
+    emit!(ModuleSponsored {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: s.module,
+        sponsor: s.sponsor,
+        escrow: e.key(),
+        mint: schedule.mint,
+        amount,
+        recurring: true,
+        sponsor_total_lamports: s.total_lamports,
+        sponsor_total_tokens: s.total_tokens,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct RemoveAttester<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [ATTESTER_SEED, registry.key().as_ref(), attester.attester.as_ref()],
+        bump = attester.bump
+    )]
+    pub attester: Account<'info, Attester>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<RemoveAttester>) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    emit!(AttesterRemoved {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        attester: ctx.accounts.attester.attester,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RenameModuleInput {
+    pub new_name: String,
+}
+
+#[derive(Accounts)]
+#[instruction(input: RenameModuleInput)]
+pub struct RenameModule<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        realloc = Module::space_for(&ModuleMeta { name: input.new_name.clone(), ..module.meta.clone() }),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        mut,
+        close = payer,
+        has_one = registry,
+        has_one = module,
+        seeds = [NAME_SEED, registry.key().as_ref(), &name_hash(&module.meta.name)],
+        bump = old_name_record.bump
+    )]
+    pub old_name_record: Account<'info, NameRecord>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = NameRecord::LEN,
+        seeds = [NAME_SEED, registry.key().as_ref(), &name_hash(&input.new_name)],
+        bump
+    )]
+    pub new_name_record: Account<'info, NameRecord>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<RenameModule>, input: RenameModuleInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    validate_module_name(&input.new_name)?;
+
+    let m = &mut ctx.accounts.module;
+    let old_name = std::mem::replace(&mut m.meta.name, input.new_name);
+    m.updated_at = Clock::get()?.unix_timestamp;
+
+    let n = &mut ctx.accounts.new_name_record;
+    n.registry = reg.key();
+    n.module = m.key();
+    n.name = normalize_module_name(&m.meta.name);
+    n.bump = ctx.bumps.new_name_record;
+
+    emit!(ModuleRenamed {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: m.key(),
+        module_id: m.module_id,
+        old_name,
+        new_name: m.meta.name.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RequestUnstakeInput {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        has_one = publisher,
+        seeds = [STAKE_SEED, registry.key().as_ref(), publisher.key().as_ref()],
+        bump = stake.bump
+    )]
+    pub stake: Account<'info, Stake>,
+
+    pub publisher: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<RequestUnstake>, input: RequestUnstakeInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let s = &mut ctx.accounts.stake;
+
+    if input.amount == 0 || input.amount > s.amount {
+        return err!(NuttooError::InvalidInput);
+    }
+    if !s.can_release(&reg.policy, input.amount) {
+        return err!(NuttooError::StakeLocked);
+    }
+
+    // A new request replaces any pending one and restarts the cooldown.
+    let now = Clock::get()?.unix_timestamp;
+    s.unstake_amount = input.amount;
+    s.unstake_requested_at = now;
+
+    emit!(UnstakeRequested {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        stake: s.key(),
+        publisher: s.publisher,
+        amount: s.unstake_amount,
+        available_at: now.saturating_add(reg.policy.unstake_cooldown_secs as i64),
+    });
+
+    Ok(())
+}
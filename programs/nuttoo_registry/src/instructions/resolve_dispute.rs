@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ResolveDisputeInput {
+    pub verdict: DisputeVerdict,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(mut, has_one = registry, has_one = original_run, has_one = challenge_run)]
+    pub dispute: Account<'info, RunDispute>,
+
+    #[account(mut)]
+    pub original_run: Account<'info, Run>,
+
+    #[account(mut)]
+    pub challenge_run: Account<'info, Run>,
+
+    /// Present when the resolver votes as a registered attester rather than the authority.
+    #[account(
+        seeds = [ATTESTER_SEED, registry.key().as_ref(), resolver.key().as_ref()],
+        bump = attester.bump
+    )]
+    pub attester: Option<Account<'info, Attester>>,
+
+    pub resolver: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<ResolveDispute>, input: ResolveDisputeInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let resolver = ctx.accounts.resolver.key();
+    let d = &mut ctx.accounts.dispute;
+
+    if d.is_resolved() {
+        return err!(NuttooError::DisputeAlreadyResolved);
+    }
+
+    // The authority decides outright; attesters vote until the policy quorum is met.
+    let verdict = if resolver == reg.authority {
+        Some(input.verdict)
+    } else if ctx.accounts.attester.is_some() {
+        if d.voters.contains(&resolver) {
+            return err!(NuttooError::AlreadyVoted);
+        }
+        if d.voters.len() >= MAX_DISPUTE_VOTERS {
+            return err!(NuttooError::PolicyViolation);
+        }
+        d.tally(resolver, input.verdict, reg.policy.dispute_quorum)
+    } else {
+        return err!(NuttooError::Unauthorized);
+    };
+
+    let Some(verdict) = verdict else {
+        return Ok(());
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    d.verdict = Some(verdict);
+    d.resolved_at = now;
+
+    // The run found correct is cleared; the other (or both, if inconclusive) stays flagged.
+    match verdict {
+        DisputeVerdict::OriginalCorrect => ctx.accounts.original_run.disputed = false,
+        DisputeVerdict::ChallengeCorrect => ctx.accounts.challenge_run.disputed = false,
+        DisputeVerdict::Inconclusive => {}
+    }
+
+    emit!(RunDisputeResolved {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        dispute: d.key(),
+        original_run: d.original_run,
+        challenge_run: d.challenge_run,
+        verdict,
+        resolver,
+    });
+
+    Ok(())
+}
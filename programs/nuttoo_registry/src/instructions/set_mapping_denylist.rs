@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetMappingDenylistInput {
+    pub denylist: Vec<Pubkey>,
+}
+
+#[derive(Accounts)]
+pub struct SetMappingDenylist<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        seeds = [MAPPING_SEED, registry.key().as_ref(), distributor.mint.as_ref()],
+        bump = distributor.bump
+    )]
+    pub distributor: Account<'info, MappingDistributor>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<SetMappingDenylist>, input: SetMappingDenylistInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    if input.denylist.len() > MAX_MAPPING_DENYLIST {
+        return err!(NuttooError::InvalidInput);
+    }
+
+    let d = &mut ctx.accounts.distributor;
+    let previous_denylist = std::mem::replace(&mut d.denylist, input.denylist);
+
+    emit!(MappingDenylistUpdated {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        distributor: d.key(),
+        denylist: d.denylist.clone(),
+        previous_denylist,
+    });
+
+    Ok(())
+}
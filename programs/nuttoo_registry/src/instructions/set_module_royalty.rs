@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetModuleRoyaltyInput {
+    pub royalty_bps: u16,
+}
+
+#[derive(Accounts)]
+pub struct SetModuleRoyalty<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(mut, has_one = registry, has_one = owner)]
+    pub module: Account<'info, Module>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<SetModuleRoyalty>, input: SetModuleRoyaltyInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let m = &mut ctx.accounts.module;
+    require_keys_eq!(m.owner, ctx.accounts.owner.key(), NuttooError::Unauthorized);
+
+    if input.royalty_bps > reg.policy.max_royalty_bps {
+        return err!(NuttooError::PolicyViolation);
+    }
+
+    let previous_royalty_bps = m.royalty_bps;
+    m.royalty_bps = input.royalty_bps;
+    m.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(ModuleRoyaltySet {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: m.key(),
+        royalty_bps: m.royalty_bps,
+        previous_royalty_bps,
+    });
+
+    Ok(())
+}
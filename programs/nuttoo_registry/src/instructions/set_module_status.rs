@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetModuleStatusInput {
+    pub status: ModuleStatus,
+}
+
+#[derive(Accounts)]
+pub struct SetModuleStatus<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+    #[account(mut)]
+    pub module: Account<'info, Module>,
+    /// CHECK: owner's publisher stake, released or re-locked when the module leaves or
+    /// re-enters service; may be uninitialized when the owner never staked.
+    #[account(mut, seeds = [STAKE_SEED, registry.key().as_ref(), module.owner.as_ref()], bump)]
+    pub stake: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<SetModuleStatus>, input: SetModuleStatusInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    let m = &mut ctx.accounts.module;
+    require_keys_eq!(m.registry, reg.key(), NuttooError::InvalidInput);
+
+    let previous_status = m.status;
+    let retiring = input.status == ModuleStatus::Retired && previous_status != ModuleStatus::Retired;
+    let reviving = previous_status == ModuleStatus::Retired && input.status != ModuleStatus::Retired;
+    let stake_info = ctx.accounts.stake.to_account_info();
+    match Stake::load_optional(&stake_info)? {
+        Some(mut s) if retiring => {
+            s.live_modules = s.live_modules.saturating_sub(1);
+            s.store(&stake_info)?;
+        }
+        Some(mut s) if reviving => {
+            if !s.covers(&reg.policy) {
+                return err!(NuttooError::InsufficientStake);
+            }
+            s.live_modules = s.live_modules.saturating_add(1);
+            s.store(&stake_info)?;
+        }
+        None if reviving && reg.policy.min_publish_stake > 0 => return err!(NuttooError::InsufficientStake),
+        _ => {}
+    }
+
+    m.status = input.status;
+    if input.status == ModuleStatus::Active {
+        m.run_stats.consecutive_failures = 0;
+    }
+    let now = Clock::get()?.unix_timestamp;
+    m.updated_at = now;
+    m.refresh_reputation(&reg.policy.reputation, now);
+
+    emit!(ModuleStatusChanged {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: m.key(),
+        module_id: m.module_id,
+        status: m.status,
+        previous_status,
+        consecutive_failures: m.run_stats.consecutive_failures,
+    });
+
+    Ok(())
+}
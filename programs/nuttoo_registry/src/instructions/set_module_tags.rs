@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetModuleTagsInput {
+    pub tags: Vec<String>,
+}
+
+#[derive(Accounts)]
+pub struct SetModuleTags<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+    #[account(mut, has_one = registry)]
+    pub module: Account<'info, Module>,
+    /// Category to file the module under; when absent the module is uncategorized.
+    #[account(has_one = registry)]
+    pub category: Option<Account<'info, Category>>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<SetModuleTags>, input: SetModuleTagsInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    validate_tags(&input.tags)?;
+
+    let m = &mut ctx.accounts.module;
+    let previous_tags = std::mem::replace(&mut m.tags, input.tags);
+    let previous_category = m.category;
+    m.category = ctx.accounts.category.as_ref().map(|c| c.key()).unwrap_or_default();
+    m.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(ModuleTagged {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: m.key(),
+        module_id: m.module_id,
+        tags: m.tags.clone(),
+        category: m.category,
+        previous_tags,
+        previous_category,
+    });
+
+    Ok(())
+}
@@ -19,12 +19,18 @@ pub fn handle(ctx: Context<SetPolicy>, input: SetPolicyInput) -> Result<()> {
     let reg = &mut ctx.accounts.registry;
     require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
 
+    input.policy.validate()?;
+
+    let previous_policy = reg.policy;
     reg.policy = input.policy;
     reg.touch(Clock::get()?.unix_timestamp);
 
     emit!(PolicyUpdated {
+        event_seq: reg.next_event_seq(),
         authority: reg.authority,
         registry: reg.key(),
+        policy: reg.policy,
+        previous_policy,
     });
 
     Ok(())
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetWorkerStatusInput {
+    pub active: bool,
+}
+
+#[derive(Accounts)]
+pub struct SetWorkerStatus<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+    #[account(mut, has_one = registry)]
+    pub worker: Account<'info, Worker>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<SetWorkerStatus>, input: SetWorkerStatusInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    let w = &mut ctx.accounts.worker;
+    let previous_active = w.active;
+    w.active = input.active;
+
+    emit!(WorkerStatusChanged {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        worker: w.worker,
+        active: w.active,
+        previous_active,
+    });
+
+    Ok(())
+}
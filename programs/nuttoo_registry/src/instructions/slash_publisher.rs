@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SlashPublisherInput {
+    pub amount: u64,
+    pub reason: String,
+}
+
+#[derive(Accounts)]
+pub struct SlashPublisher<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    /// Module whose attestation is revoked.
+    #[account(mut, has_one = registry)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        seeds = [STAKE_SEED, registry.key().as_ref(), module.owner.as_ref()],
+        bump = stake.bump
+    )]
+    pub stake: Account<'info, Stake>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        seeds = [TREASURY_SEED, registry.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Present when the slasher is a council attester rather than the authority;
+    /// attesters may only slash once the module's attestation has been revoked.
+    #[account(
+        seeds = [ATTESTER_SEED, registry.key().as_ref(), slasher.key().as_ref()],
+        bump = attester.bump
+    )]
+    pub attester: Option<Account<'info, Attester>>,
+
+    pub slasher: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<SlashPublisher>, input: SlashPublisherInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let slasher = ctx.accounts.slasher.key();
+    if slasher != reg.authority {
+        if ctx.accounts.attester.is_none() {
+            return err!(NuttooError::Unauthorized);
+        }
+        if ctx.accounts.module.verified {
+            return err!(NuttooError::AttestationNotRevoked);
+        }
+    }
+
+    require_nonempty(&input.reason)?;
+    require_max_len(&input.reason, MAX_SLASH_REASON_LEN)?;
+
+    let s = &mut ctx.accounts.stake;
+    let amount = input.amount.min(s.amount);
+    if amount == 0 {
+        return err!(NuttooError::InsufficientStake);
+    }
+
+    let stake_info = s.to_account_info();
+    let t = &mut ctx.accounts.treasury;
+    **stake_info.try_borrow_mut_lamports()? -= amount;
+    **t.to_account_info().try_borrow_mut_lamports()? += amount;
+    t.total_collected = t.total_collected.saturating_add(amount);
+
+    s.amount -= amount;
+    s.unstake_amount = s.unstake_amount.min(s.amount);
+    s.slashed_total = s.slashed_total.saturating_add(amount);
+
+    let m = &mut ctx.accounts.module;
+    let previous_verified = m.verified;
+    let now = Clock::get()?.unix_timestamp;
+    m.verified = false;
+    m.updated_at = now;
+    m.record_attestation(previous_verified, false, &reg.policy.reputation, now);
+
+    emit!(PublisherSlashed {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: m.key(),
+        publisher: s.publisher,
+        stake: s.key(),
+        slasher,
+        amount,
+        remaining: s.amount,
+        previous_verified,
+        reason: input.reason,
+    });
+
+    Ok(())
+}
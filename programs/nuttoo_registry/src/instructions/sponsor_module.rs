@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SponsorScheduleInput {
+    pub period_secs: u32,
+    pub periods: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SponsorModuleInput {
+    /// One-off amount, or the per-period amount when `schedule` is set.
+    pub amount: u64,
+    pub schedule: Option<SponsorScheduleInput>,
+}
+
+#[derive(Accounts)]
+pub struct SponsorModule<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(has_one = registry)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = SponsorEscrow::LEN,
+        seeds = [SPONSOR_ESCROW_SEED, module.key().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, SponsorEscrow>,
+
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = Sponsorship::LEN,
+        seeds = [SPONSORSHIP_SEED, module.key().as_ref(), sponsor.key().as_ref()],
+        bump
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+
+    /// Present to sponsor in the registry's configured mint. `vault` belongs
+    /// to `escrow` for one-off tips and to `sponsorship` for schedules.
+    pub escrow_tokens: EscrowTokenAccounts<'info>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<SponsorModule>, input: SponsorModuleInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let module = ctx.accounts.module.key();
+    let sponsor = ctx.accounts.sponsor.key();
+
+    if input.amount == 0 {
+        return err!(NuttooError::InvalidInput);
+    }
+    let mint = ctx.accounts.escrow_tokens.mint_key();
+    if mint.is_some() && mint != reg.policy.fee_mint {
+        return err!(NuttooError::InvalidFeeAccounts);
+    }
+
+    let e = &mut ctx.accounts.escrow;
+    if e.registry == Pubkey::default() {
+        e.registry = reg.key();
+        e.module = module;
+        e.bump = ctx.bumps.escrow;
+    }
+    let s = &mut ctx.accounts.sponsorship;
+    if s.registry == Pubkey::default() {
+        s.registry = reg.key();
+        s.module = module;
+        s.sponsor = sponsor;
+        s.bump = ctx.bumps.sponsorship;
+    }
+
+    let sponsor_info = ctx.accounts.sponsor.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let Some(schedule) = input.schedule else {
+        let received = escrow_deposit(&e.to_account_info(), mint, &ctx.accounts.escrow_tokens, &sponsor_info, &system_program, input.amount)?;
+        e.credit(mint, received);
+        s.credit(mint, received);
+
+        emit!(ModuleSponsored {
+            event_seq: reg.next_event_seq(),
+            registry: reg.key(),
+            module,
+            sponsor,
+            escrow: e.key(),
+            mint,
+            amount: received,
+            recurring: false,
+            sponsor_total_lamports: s.total_lamports,
+            sponsor_total_tokens: s.total_tokens,
+        });
+        return Ok(());
+    };
+
+    if schedule.period_secs == 0 || schedule.periods == 0 || s.schedule.is_some() {
+        return err!(NuttooError::InvalidInput);
+    }
+    let total = input
+        .amount
+        .checked_mul(schedule.periods as u64)
+        .ok_or(NuttooError::InvalidInput)?;
+    escrow_deposit(&s.to_account_info(), mint, &ctx.accounts.escrow_tokens, &sponsor_info, &system_program, total)?;
+
+    // The first installment is due immediately and released by `release_sponsorship`.
+    let now = Clock::get()?.unix_timestamp;
+    s.schedule = Some(SponsorSchedule {
+        mint,
+        amount_per_period: input.amount,
+        period_secs: schedule.period_secs,
+        remaining_periods: schedule.periods,
+        next_due_at: now,
+    });
+
+    emit!(SponsorshipScheduled {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module,
+        sponsor,
+        sponsorship: s.key(),
+        mint,
+        amount_per_period: input.amount,
+        period_secs: schedule.period_secs,
+        periods: schedule.periods,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SubmitReviewInput {
+    pub version: String,
+    pub rating: u8,
+    pub flags: u8,
+    pub comment_sha256: [u8; 32],
+}
+
+#[derive(Accounts)]
+#[instruction(input: SubmitReviewInput)]
+pub struct SubmitReview<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(mut, has_one = registry)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        init_if_needed,
+        payer = reviewer,
+        space = ModuleVersion::LEN,
+        seeds = [VERSION_SEED, module.key().as_ref(), input.version.as_bytes()],
+        bump
+    )]
+    pub module_version: Account<'info, ModuleVersion>,
+
+    #[account(
+        init_if_needed,
+        payer = reviewer,
+        space = Review::LEN,
+        seeds = [REVIEW_SEED, module_version.key().as_ref(), reviewer.key().as_ref()],
+        bump
+    )]
+    pub review: Account<'info, Review>,
+
+    /// Run of the reviewed version; required when `policy.review_requires_run` is set.
+    #[account(has_one = registry, has_one = module)]
+    pub run: Option<Account<'info, Run>>,
+
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<SubmitReview>, input: SubmitReviewInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let m = &mut ctx.accounts.module;
+    let reviewer = ctx.accounts.reviewer.key();
+
+    if !(1..=5).contains(&input.rating) || input.flags & !Review::FLAGS_MASK != 0 {
+        return err!(NuttooError::InvalidInput);
+    }
+    if reviewer == m.owner {
+        return err!(NuttooError::Unauthorized);
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let v = &mut ctx.accounts.module_version;
+    if v.created_at == 0 {
+        // Only the current version can be reviewed before it has per-version state.
+        if input.version != m.meta.version {
+            return err!(NuttooError::InvalidInput);
+        }
+        v.registry = reg.key();
+        v.module = m.key();
+        v.version = input.version;
+        v.created_at = now;
+        v.artifact_count = 0;
+        v.artifact_bytes = 0;
+        v.bump = ctx.bumps.module_version;
+    }
+
+    if reg.policy.review_requires_run {
+        let ran = ctx.accounts.run.as_ref().is_some_and(|r| {
+            r.module_version == v.version && (r.owner == reviewer || r.worker == reviewer)
+        });
+        if !ran {
+            return err!(NuttooError::ReviewRequiresRun);
+        }
+    }
+
+    let r = &mut ctx.accounts.review;
+
+    let previous_rating = if r.is_new() {
+        r.registry = reg.key();
+        r.module = m.key();
+        r.module_version = v.key();
+        r.reviewer = reviewer;
+        r.created_at = now;
+        r.bump = ctx.bumps.review;
+        None
+    } else {
+        m.remove_review(r.rating, r.flags);
+        Some(r.rating)
+    };
+
+    r.rating = input.rating;
+    r.flags = input.flags;
+    r.comment_sha256 = input.comment_sha256;
+    r.updated_at = now;
+    m.add_review(r.rating, r.flags);
+
+    emit!(ModuleReviewed {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: m.key(),
+        module_version: v.key(),
+        version: v.version.clone(),
+        review: r.key(),
+        reviewer,
+        rating: r.rating,
+        flags: r.flags,
+        comment_sha256: r.comment_sha256,
+        previous_rating,
+        review_count: m.review_count,
+        average_rating_x100: m.average_rating_x100(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct UnyankVersion<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(has_one = registry)]
+    pub module: Account<'info, Module>,
+
+    #[account(mut, has_one = registry, has_one = module)]
+    pub module_version: Account<'info, ModuleVersion>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<UnyankVersion>) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    let v = &mut ctx.accounts.module_version;
+    if !v.yanked {
+        return err!(NuttooError::InvalidInput);
+    }
+    v.yanked = false;
+    v.yanked_at = 0;
+    v.yank_reason = String::new();
+
+    emit!(VersionUnyanked {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: ctx.accounts.module.key(),
+        module_version: v.key(),
+        version: v.version.clone(),
+    });
+
+    Ok(())
+}
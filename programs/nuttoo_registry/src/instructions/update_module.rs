@@ -3,6 +3,7 @@ use crate::state::*;
 use crate::errors::NuttooError;
 use crate::events::*;
 use crate::utils::*;
+use crate::manifest::Manifest;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct UpdateModuleInput {
@@ -11,6 +12,8 @@ pub struct UpdateModuleInput {
     pub description: String,
     pub uri: String,
     pub manifest_sha256: [u8; 32],
+    /// Canonical manifest bytes; when present the program hashes them itself.
+    pub manifest: Option<Vec<u8>>,
 }
 
 #[derive(Accounts)]
@@ -27,10 +30,31 @@ pub fn handle(ctx: Context<UpdateModule>, input: UpdateModuleInput) -> Result<()
     require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
 
     validate_module_meta(&input.name, &input.version, &input.description, &input.uri)?;
+    validate_module_name(&input.name)?;
+
+    if let Some(bytes) = input.manifest.as_ref() {
+        let manifest = Manifest::decode_canonical(bytes)?;
+        if manifest.name != input.name || manifest.version != input.version {
+            return err!(NuttooError::InvalidInput);
+        }
+        if sha256_32(bytes) != input.manifest_sha256 {
+            return err!(NuttooError::ManifestHashMismatch);
+        }
+    }
 
     let m = &mut ctx.accounts.module;
     require_keys_eq!(m.registry, reg.key(), NuttooError::InvalidInput);
 
+    // Names are claimed through NameRecord; changing the claimed name goes through rename_module.
+    if normalize_module_name(&input.name) != normalize_module_name(&m.meta.name) {
+        return err!(NuttooError::InvalidInput);
+    }
+
+    check_content_addressed_uri(&reg.policy, &input.uri, &m.artifact_sha256)?;
+
+    let previous_meta = m.meta.clone();
+    let previous_manifest_sha256 = m.manifest_sha256;
+
     m.meta.name = input.name;
     m.meta.version = input.version;
     m.meta.description = input.description;
@@ -39,9 +63,15 @@ pub fn handle(ctx: Context<UpdateModule>, input: UpdateModuleInput) -> Result<()
     m.updated_at = Clock::get()?.unix_timestamp;
 
     emit!(ModuleUpdated {
+        event_seq: reg.next_event_seq(),
         registry: reg.key(),
         module: m.key(),
         module_id: m.module_id,
+        meta: m.meta.clone(),
+        manifest_sha256: m.manifest_sha256,
+        previous_meta,
+        previous_manifest_sha256,
+        updated_at: m.updated_at,
     });
 
     Ok(())
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VerifyRunInclusionInput {
+    pub run_id: [u8; 32],
+    pub inputs_sha256: [u8; 32],
+    pub outputs_sha256: [u8; 32],
+    pub ok: bool,
+    pub exit_code: i32,
+    pub finished_at: i64,
+    pub index: u32,
+    pub proof: Vec<[u8; 32]>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyRunInclusion<'info> {
+    pub registry: Account<'info, Registry>,
+    #[account(has_one = registry)]
+    pub run_batch: Account<'info, RunBatch>,
+}
+
+pub fn handle(ctx: Context<VerifyRunInclusion>, input: VerifyRunInclusionInput) -> Result<()> {
+    let b = &ctx.accounts.run_batch;
+
+    if input.index >= b.count || input.proof.len() > MAX_MERKLE_DEPTH {
+        return err!(NuttooError::InvalidProof);
+    }
+    if input.finished_at < b.first_run_at || input.finished_at > b.last_run_at {
+        return err!(NuttooError::InvalidProof);
+    }
+
+    let leaf = run_receipt_leaf(
+        &input.run_id,
+        &b.module,
+        &input.inputs_sha256,
+        &input.outputs_sha256,
+        input.ok,
+        input.exit_code,
+        input.finished_at,
+    );
+    if !verify_merkle_proof(&b.merkle_root, &leaf, input.index as u64, &input.proof) {
+        return err!(NuttooError::InvalidProof);
+    }
+
+    emit!(RunInclusionVerified {
+        run_batch: b.key(),
+        run_id: input.run_id,
+        index: input.index,
+        leaf,
+    });
+
+    Ok(())
+}
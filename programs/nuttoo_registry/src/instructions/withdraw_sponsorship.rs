@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WithdrawSponsorshipInput {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSponsorship<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(has_one = registry, has_one = owner)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        has_one = module,
+        seeds = [SPONSOR_ESCROW_SEED, module.key().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, SponsorEscrow>,
+
+    /// Present to withdraw tokens; `counterparty` is the owner's token account.
+    pub escrow_tokens: EscrowTokenAccounts<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<WithdrawSponsorship>, input: WithdrawSponsorshipInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let e = &mut ctx.accounts.escrow;
+
+    if input.amount == 0 {
+        return err!(NuttooError::InvalidInput);
+    }
+
+    let mint = ctx.accounts.escrow_tokens.mint_key();
+    let seeds: &[&[u8]] = &[SPONSOR_ESCROW_SEED, e.module.as_ref(), &[e.bump]];
+    let withdrawn = escrow_release(
+        &e.to_account_info(),
+        seeds,
+        mint,
+        &ctx.accounts.escrow_tokens,
+        &ctx.accounts.owner.to_account_info(),
+        input.amount,
+    )?;
+    match mint {
+        Some(_) => e.tokens_withdrawn = e.tokens_withdrawn.saturating_add(withdrawn),
+        None => e.lamports_withdrawn = e.lamports_withdrawn.saturating_add(withdrawn),
+    }
+
+    emit!(SponsorshipWithdrawn {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: e.module,
+        escrow: e.key(),
+        owner: ctx.accounts.owner.key(),
+        mint,
+        amount: withdrawn,
+    });
+
+    Ok(())
+}
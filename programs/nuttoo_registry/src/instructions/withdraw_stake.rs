@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        has_one = publisher,
+        seeds = [STAKE_SEED, registry.key().as_ref(), publisher.key().as_ref()],
+        bump = stake.bump
+    )]
+    pub stake: Account<'info, Stake>,
+
+    #[account(mut)]
+    pub publisher: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<WithdrawStake>) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    let s = &mut ctx.accounts.stake;
+
+    if s.unstake_amount == 0 {
+        return err!(NuttooError::InvalidInput);
+    }
+    let now = Clock::get()?.unix_timestamp;
+    if now < s.unstake_requested_at.saturating_add(reg.policy.unstake_cooldown_secs as i64) {
+        return err!(NuttooError::UnstakeCooldown);
+    }
+
+    // Slashing during the cooldown may have left less than was requested.
+    let amount = s.unstake_amount.min(s.amount);
+    if !s.can_release(&reg.policy, amount) {
+        return err!(NuttooError::StakeLocked);
+    }
+
+    let info = s.to_account_info();
+    **info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.publisher.try_borrow_mut_lamports()? += amount;
+    s.amount -= amount;
+    s.unstake_amount = 0;
+    s.unstake_requested_at = 0;
+
+    emit!(StakeWithdrawn {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        stake: s.key(),
+        publisher: s.publisher,
+        amount,
+        remaining: s.amount,
+    });
+
+    Ok(())
+}
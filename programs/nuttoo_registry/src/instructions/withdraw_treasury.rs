@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WithdrawTreasuryInput {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        has_one = registry,
+        seeds = [TREASURY_SEED, registry.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: any account may receive lamports.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<WithdrawTreasury>, input: WithdrawTreasuryInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    if input.amount == 0 {
+        return err!(NuttooError::InvalidInput);
+    }
+
+    let t = &mut ctx.accounts.treasury;
+    Treasury::pay_out(t, &ctx.accounts.recipient, input.amount)?;
+
+    emit!(TreasuryWithdrawn {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        treasury: t.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount: input.amount,
+        total_withdrawn: t.total_withdrawn,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WithdrawTreasuryTokensInput {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasuryTokens<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        has_one = registry,
+        seeds = [TREASURY_SEED, registry.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Any mint the treasury holds; not necessarily the current `Policy::fee_mint`.
+    #[account(mint::token_program = token_program)]
+    pub fee_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = fee_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = fee_mint,
+        token::token_program = token_program
+    )]
+    pub recipient: InterfaceAccount<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle(ctx: Context<WithdrawTreasuryTokens>, input: WithdrawTreasuryTokensInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    if input.amount == 0 {
+        return err!(NuttooError::InvalidInput);
+    }
+    if input.amount > ctx.accounts.fee_vault.amount {
+        return err!(NuttooError::InsufficientTreasury);
+    }
+
+    let registry_key = reg.key();
+    let seeds: &[&[u8]] = &[TREASURY_SEED, registry_key.as_ref(), &[ctx.accounts.treasury.bump]];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.fee_vault.to_account_info(),
+                mint: ctx.accounts.fee_mint.to_account_info(),
+                to: ctx.accounts.recipient.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            &[seeds],
+        ),
+        input.amount,
+        ctx.accounts.fee_mint.decimals,
+    )?;
+
+    emit!(TreasuryTokensWithdrawn {
+        event_seq: reg.next_event_seq(),
+        registry: registry_key,
+        treasury: ctx.accounts.treasury.key(),
+        fee_mint: ctx.accounts.fee_mint.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount: input.amount,
+    });
+
+    Ok(())
+}
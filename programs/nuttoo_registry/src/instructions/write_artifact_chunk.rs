@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WriteArtifactChunkInput {
+    pub offset: u32,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Accounts)]
+pub struct WriteArtifactChunk<'info> {
+    #[account(has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(has_one = registry)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        mut,
+        seeds = [ARTIFACT_BUFFER_SEED, registry.key().as_ref(), module.key().as_ref()],
+        bump
+    )]
+    pub artifact_buffer: AccountLoader<'info, ArtifactBuffer>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle(ctx: Context<WriteArtifactChunk>, input: WriteArtifactChunkInput) -> Result<()> {
+    let reg = &ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    if input.bytes.is_empty() {
+        return err!(NuttooError::InvalidInput);
+    }
+
+    ctx.accounts.artifact_buffer.load()?;
+    let info = ctx.accounts.artifact_buffer.to_account_info();
+    let mut data = info.try_borrow_mut_data()?;
+    let (buf, bytes) = ArtifactBuffer::split_mut(&mut data)?;
+
+    if buf.finalized != 0 {
+        return err!(NuttooError::ArtifactFinalized);
+    }
+
+    let start = input.offset as usize;
+    let end = start.checked_add(input.bytes.len()).ok_or(NuttooError::InvalidInput)?;
+    if end > bytes.len() {
+        return err!(NuttooError::ArtifactTooLarge);
+    }
+    bytes[start..end].copy_from_slice(&input.bytes);
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct YankVersionInput {
+    pub version: String,
+    pub reason: String,
+}
+
+#[derive(Accounts)]
+#[instruction(input: YankVersionInput)]
+pub struct YankVersion<'info> {
+    #[account(mut, has_one = authority)]
+    pub registry: Account<'info, Registry>,
+
+    #[account(has_one = registry)]
+    pub module: Account<'info, Module>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ModuleVersion::LEN,
+        seeds = [VERSION_SEED, module.key().as_ref(), input.version.as_bytes()],
+        bump
+    )]
+    pub module_version: Account<'info, ModuleVersion>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<YankVersion>, input: YankVersionInput) -> Result<()> {
+    let reg = &mut ctx.accounts.registry;
+    require_keys_eq!(reg.authority, ctx.accounts.authority.key(), NuttooError::Unauthorized);
+
+    require_nonempty(&input.reason)?;
+    require_max_len(&input.reason, MAX_YANK_REASON_LEN)?;
+
+    let m = &ctx.accounts.module;
+    let now = Clock::get()?.unix_timestamp;
+
+    let v = &mut ctx.accounts.module_version;
+    if v.created_at == 0 {
+        // Only the current version can be yanked before it has per-version state.
+        if input.version != m.meta.version {
+            return err!(NuttooError::InvalidInput);
+        }
+        v.registry = reg.key();
+        v.module = m.key();
+        v.version = input.version;
+        v.created_at = now;
+        v.artifact_count = 0;
+        v.artifact_bytes = 0;
+        v.bump = ctx.bumps.module_version;
+    }
+
+    v.yanked = true;
+    v.yanked_at = now;
+    v.yank_reason = input.reason;
+
+    emit!(VersionYanked {
+        event_seq: reg.next_event_seq(),
+        registry: reg.key(),
+        module: m.key(),
+        module_version: v.key(),
+        version: v.version.clone(),
+        reason: v.yank_reason.clone(),
+    });
+
+    Ok(())
+}
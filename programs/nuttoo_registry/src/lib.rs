@@ -5,6 +5,7 @@ pub mod errors;
 pub mod events;
 pub mod state;
 pub mod instructions;
+pub mod manifest;
 pub mod utils;
 
 use instructions::*;
@@ -39,7 +40,183 @@ pub mod nuttoo_registry {
         instructions::attest_artifact::handle(ctx, input)
     }
 
-    pub fn record_run(ctx: Context<RecordRun>, input: RecordRunInput) -> Result<()> {
+    pub fn record_run<'info>(ctx: Context<'_, '_, 'info, 'info, RecordRun<'info>>, input: RecordRunInput) -> Result<()> {
         instructions::record_run::handle(ctx, input)
     }
+
+    pub fn add_attester(ctx: Context<AddAttester>, input: AddAttesterInput) -> Result<()> {
+        instructions::add_attester::handle(ctx, input)
+    }
+
+    pub fn remove_attester(ctx: Context<RemoveAttester>) -> Result<()> {
+        instructions::remove_attester::handle(ctx)
+    }
+
+    pub fn challenge_run<'info>(ctx: Context<'_, '_, 'info, 'info, ChallengeRun<'info>>, input: ChallengeRunInput) -> Result<()> {
+        instructions::challenge_run::handle(ctx, input)
+    }
+
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, input: ResolveDisputeInput) -> Result<()> {
+        instructions::resolve_dispute::handle(ctx, input)
+    }
+
+    pub fn set_module_status(ctx: Context<SetModuleStatus>, input: SetModuleStatusInput) -> Result<()> {
+        instructions::set_module_status::handle(ctx, input)
+    }
+
+    pub fn register_worker(ctx: Context<RegisterWorker>, input: RegisterWorkerInput) -> Result<()> {
+        instructions::register_worker::handle(ctx, input)
+    }
+
+    pub fn set_worker_status(ctx: Context<SetWorkerStatus>, input: SetWorkerStatusInput) -> Result<()> {
+        instructions::set_worker_status::handle(ctx, input)
+    }
+
+    pub fn init_run_log(ctx: Context<InitRunLog>, input: InitRunLogInput) -> Result<()> {
+        instructions::init_run_log::handle(ctx, input)
+    }
+
+    pub fn record_runs_batch<'info>(ctx: Context<'_, '_, 'info, 'info, RecordRunsBatch<'info>>, input: RecordRunsBatchInput) -> Result<()> {
+        instructions::record_runs_batch::handle(ctx, input)
+    }
+
+    pub fn commit_run_batch<'info>(ctx: Context<'_, '_, 'info, 'info, CommitRunBatch<'info>>, input: CommitRunBatchInput) -> Result<()> {
+        instructions::commit_run_batch::handle(ctx, input)
+    }
+
+    pub fn verify_run_inclusion(ctx: Context<VerifyRunInclusion>, input: VerifyRunInclusionInput) -> Result<()> {
+        instructions::verify_run_inclusion::handle(ctx, input)
+    }
+
+    pub fn init_artifact_buffer(ctx: Context<InitArtifactBuffer>) -> Result<()> {
+        instructions::init_artifact_buffer::handle(ctx)
+    }
+
+    pub fn write_artifact_chunk(ctx: Context<WriteArtifactChunk>, input: WriteArtifactChunkInput) -> Result<()> {
+        instructions::write_artifact_chunk::handle(ctx, input)
+    }
+
+    pub fn finalize_artifact(ctx: Context<FinalizeArtifact>) -> Result<()> {
+        instructions::finalize_artifact::handle(ctx)
+    }
+
+    pub fn add_artifact(ctx: Context<AddArtifact>, input: AddArtifactInput) -> Result<()> {
+        instructions::add_artifact::handle(ctx, input)
+    }
+
+    pub fn create_category(ctx: Context<CreateCategory>, input: CreateCategoryInput) -> Result<()> {
+        instructions::create_category::handle(ctx, input)
+    }
+
+    pub fn set_module_tags(ctx: Context<SetModuleTags>, input: SetModuleTagsInput) -> Result<()> {
+        instructions::set_module_tags::handle(ctx, input)
+    }
+
+    pub fn rename_module(ctx: Context<RenameModule>, input: RenameModuleInput) -> Result<()> {
+        instructions::rename_module::handle(ctx, input)
+    }
+
+    pub fn yank_version(ctx: Context<YankVersion>, input: YankVersionInput) -> Result<()> {
+        instructions::yank_version::handle(ctx, input)
+    }
+
+    pub fn unyank_version(ctx: Context<UnyankVersion>) -> Result<()> {
+        instructions::unyank_version::handle(ctx)
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, input: WithdrawTreasuryInput) -> Result<()> {
+        instructions::withdraw_treasury::handle(ctx, input)
+    }
+
+    pub fn init_fee_vault(ctx: Context<InitFeeVault>) -> Result<()> {
+        instructions::init_fee_vault::handle(ctx)
+    }
+
+    pub fn withdraw_treasury_tokens(ctx: Context<WithdrawTreasuryTokens>, input: WithdrawTreasuryTokensInput) -> Result<()> {
+        instructions::withdraw_treasury_tokens::handle(ctx, input)
+    }
+
+    pub fn set_module_royalty(ctx: Context<SetModuleRoyalty>, input: SetModuleRoyaltyInput) -> Result<()> {
+        instructions::set_module_royalty::handle(ctx, input)
+    }
+
+    pub fn claim_royalties(ctx: Context<ClaimRoyalties>, input: ClaimRoyaltiesInput) -> Result<()> {
+        instructions::claim_royalties::handle(ctx, input)
+    }
+
+    pub fn post_bounty(ctx: Context<PostBounty>, input: PostBountyInput) -> Result<()> {
+        instructions::post_bounty::handle(ctx, input)
+    }
+
+    pub fn claim_bounty(ctx: Context<ClaimBounty>) -> Result<()> {
+        instructions::claim_bounty::handle(ctx)
+    }
+
+    pub fn cancel_bounty(ctx: Context<CancelBounty>) -> Result<()> {
+        instructions::cancel_bounty::handle(ctx)
+    }
+
+    pub fn deposit_stake(ctx: Context<DepositStake>, input: DepositStakeInput) -> Result<()> {
+        instructions::deposit_stake::handle(ctx, input)
+    }
+
+    pub fn request_unstake(ctx: Context<RequestUnstake>, input: RequestUnstakeInput) -> Result<()> {
+        instructions::request_unstake::handle(ctx, input)
+    }
+
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+        instructions::withdraw_stake::handle(ctx)
+    }
+
+    pub fn slash_publisher(ctx: Context<SlashPublisher>, input: SlashPublisherInput) -> Result<()> {
+        instructions::slash_publisher::handle(ctx, input)
+    }
+
+    pub fn init_mapping_distributor(ctx: Context<InitMappingDistributor>, input: InitMappingDistributorInput) -> Result<()> {
+        instructions::init_mapping_distributor::handle(ctx, input)
+    }
+
+    pub fn set_mapping_denylist(ctx: Context<SetMappingDenylist>, input: SetMappingDenylistInput) -> Result<()> {
+        instructions::set_mapping_denylist::handle(ctx, input)
+    }
+
+    pub fn claim_mapped_tokens(ctx: Context<ClaimMappedTokens>, input: ClaimMappedTokensInput) -> Result<()> {
+        instructions::claim_mapped_tokens::handle(ctx, input)
+    }
+
+    pub fn open_reward_epoch(ctx: Context<OpenRewardEpoch>, input: OpenRewardEpochInput) -> Result<()> {
+        instructions::open_reward_epoch::handle(ctx, input)
+    }
+
+    pub fn claim_epoch_reward(ctx: Context<ClaimEpochReward>) -> Result<()> {
+        instructions::claim_epoch_reward::handle(ctx)
+    }
+
+    pub fn close_reward_epoch(ctx: Context<CloseRewardEpoch>) -> Result<()> {
+        instructions::close_reward_epoch::handle(ctx)
+    }
+
+    pub fn sponsor_module(ctx: Context<SponsorModule>, input: SponsorModuleInput) -> Result<()> {
+        instructions::sponsor_module::handle(ctx, input)
+    }
+
+    pub fn release_sponsorship(ctx: Context<ReleaseSponsorship>) -> Result<()> {
+        instructions::release_sponsorship::handle(ctx)
+    }
+
+    pub fn cancel_sponsorship(ctx: Context<CancelSponsorship>) -> Result<()> {
+        instructions::cancel_sponsorship::handle(ctx)
+    }
+
+    pub fn withdraw_sponsorship(ctx: Context<WithdrawSponsorship>, input: WithdrawSponsorshipInput) -> Result<()> {
+        instructions::withdraw_sponsorship::handle(ctx, input)
+    }
+
+    pub fn recompute_reputation(ctx: Context<RecomputeReputation>) -> Result<()> {
+        instructions::recompute_reputation::handle(ctx)
+    }
+
+    pub fn submit_review(ctx: Context<SubmitReview>, input: SubmitReviewInput) -> Result<()> {
+        instructions::submit_review::handle(ctx, input)
+    }
 }
@@ -0,0 +1,164 @@
+//! Canonical module manifest encoding.
+//!
+//! The canonical form is the Borsh encoding of [`Manifest`] with fields in
+//! declaration order and `inputs`/`outputs` sorted by name without duplicates.
+//! Off-chain tools build a `Manifest` from JSON, call [`Manifest::canonicalize`]
+//! and hash [`Manifest::canonical_bytes`]; the program decodes the same bytes
+//! with [`Manifest::decode_canonical`] and hashes them itself.
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ManifestRuntime {
+    pub kind: String,
+    pub version: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ManifestField {
+    pub name: String,
+    pub ty: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Manifest {
+    pub name: String,
+    pub version: String,
+    pub description: String,
+    pub entry_file: String,
+    pub runtime: ManifestRuntime,
+    pub inputs: Vec<ManifestField>,
+    pub outputs: Vec<ManifestField>,
+}
+
+impl Manifest {
+    /// Sorts `inputs` and `outputs` into canonical order.
+    pub fn canonicalize(&mut self) {
+        self.inputs.sort_by(|a, b| a.name.cmp(&b.name));
+        self.outputs.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    pub fn canonical_bytes(&self) -> Result<Vec<u8>> {
+        self.validate()?;
+        self.try_to_vec().map_err(|_| error!(NuttooError::InvalidInput))
+    }
+
+    pub fn canonical_hash(&self) -> Result<[u8; 32]> {
+        Ok(sha256_32(&self.canonical_bytes()?))
+    }
+
+    /// Decodes manifest bytes, rejecting anything that is not already canonical.
+    pub fn decode_canonical(bytes: &[u8]) -> Result<Manifest> {
+        if bytes.len() > MAX_MANIFEST_BYTES {
+            return err!(NuttooError::InvalidInput);
+        }
+        let mut data = bytes;
+        let manifest = Manifest::deserialize(&mut data).map_err(|_| error!(NuttooError::InvalidInput))?;
+        if !data.is_empty() {
+            return err!(NuttooError::InvalidInput);
+        }
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        validate_module_meta(&self.name, &self.version, &self.description, "")?;
+        require_nonempty(&self.entry_file)?;
+        require_max_len(&self.entry_file, MAX_ENTRY_FILE_LEN)?;
+        require_nonempty(&self.runtime.kind)?;
+        require_max_len(&self.runtime.kind, MAX_RUNTIME_KIND_LEN)?;
+        require_max_len(&self.runtime.version, MAX_RUNTIME_VERSION_LEN)?;
+        validate_manifest_fields(&self.inputs)?;
+        validate_manifest_fields(&self.outputs)?;
+        Ok(())
+    }
+}
+
+fn validate_manifest_fields(fields: &[ManifestField]) -> Result<()> {
+    if fields.len() > MAX_MANIFEST_FIELDS {
+        return err!(NuttooError::InvalidInput);
+    }
+    for f in fields {
+        require_nonempty(&f.name)?;
+        require_max_len(&f.name, MAX_MANIFEST_FIELD_NAME_LEN)?;
+        require_nonempty(&f.ty)?;
+        require_max_len(&f.ty, MAX_MANIFEST_FIELD_TYPE_LEN)?;
+    }
+    // Strictly ascending names: canonical order and no duplicates.
+    if fields.windows(2).any(|w| w[0].name >= w[1].name) {
+        return err!(NuttooError::InvalidInput);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, ty: &str) -> ManifestField {
+        ManifestField { name: name.into(), ty: ty.into() }
+    }
+
+    fn sample() -> Manifest {
+        Manifest {
+            name: "demo-module".into(),
+            version: "0.1.0".into(),
+            description: "Fixture module manifest".into(),
+            entry_file: "index.mjs".into(),
+            runtime: ManifestRuntime { kind: "node".into(), version: ">=18".into() },
+            inputs: vec![field("zeta", "string"), field("example", "string")],
+            outputs: vec![field("result", "string")],
+        }
+    }
+
+    #[test]
+    fn canonical_round_trip() {
+        let mut m = sample();
+        m.canonicalize();
+        let bytes = m.canonical_bytes().unwrap();
+        assert_eq!(Manifest::decode_canonical(&bytes).unwrap(), m);
+        assert_eq!(m.canonical_hash().unwrap(), sha256_32(&bytes));
+    }
+
+    /// Bytes produced by `encodeCanonicalManifest` in `@nuttoo/module-kit` for `sample()`.
+    const MODULE_KIT_HEX: &str = "0b00000064656d6f2d6d6f64756c6505000000302e312e301700000046697874757265206d6f64756c65206d616e696665737409000000696e6465782e6d6a73040000006e6f6465040000003e3d313802000000070000006578616d706c6506000000737472696e67040000007a65746106000000737472696e670100000006000000726573756c7406000000737472696e67";
+
+    #[test]
+    fn matches_off_chain_encoder() {
+        let mut m = sample();
+        m.canonicalize();
+        let expected: Vec<u8> = (0..MODULE_KIT_HEX.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&MODULE_KIT_HEX[i..i + 2], 16).unwrap())
+            .collect();
+        assert_eq!(m.canonical_bytes().unwrap(), expected);
+    }
+
+    #[test]
+    fn rejects_unsorted_fields() {
+        let m = sample();
+        assert!(m.canonical_bytes().is_err());
+        let bytes = m.try_to_vec().unwrap();
+        assert!(Manifest::decode_canonical(&bytes).is_err());
+    }
+
+    #[test]
+    fn rejects_duplicate_fields() {
+        let mut m = sample();
+        m.outputs = vec![field("result", "string"), field("result", "number")];
+        let bytes = m.try_to_vec().unwrap();
+        assert!(Manifest::decode_canonical(&bytes).is_err());
+    }
+
+    #[test]
+    fn rejects_trailing_bytes() {
+        let mut m = sample();
+        m.canonicalize();
+        let mut bytes = m.canonical_bytes().unwrap();
+        bytes.push(0);
+        assert!(Manifest::decode_canonical(&bytes).is_err());
+    }
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+pub struct Artifact {
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub module_version: Pubkey,
+    pub version: String,
+    pub target: String,
+    pub artifact_sha256: [u8; 32],
+    pub size: u64,
+    pub uri: String,
+    pub verified: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl Artifact {
+    pub fn space_for(version_len: usize, target_len: usize, uri_len: usize) -> usize {
+        8 + // disc
+        32 + 32 + 32 + // registry, module, module_version
+        4 + version_len +
+        4 + target_len +
+        32 + // artifact hash
+        8 + // size
+        4 + uri_len +
+        1 + // verified
+        8 + 8 + // timestamps
+        1 + // bump
+        DEFAULT_BUMP_PADDING
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::errors::NuttooError;
+
+/// Header of an on-chain artifact; `size` raw bytes follow it in the account data.
+#[account(zero_copy)]
+pub struct ArtifactBuffer {
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub finalized_at: i64,
+    pub size: u32,
+    pub finalized: u8,
+    pub bump: u8,
+    pub _padding: [u8; 2],
+}
+
+impl ArtifactBuffer {
+    pub const DATA_OFFSET: usize = 8 + std::mem::size_of::<ArtifactBuffer>();
+
+    pub fn space_for(size: u64) -> usize {
+        Self::DATA_OFFSET + size as usize
+    }
+
+    /// Splits raw account data into the header and the artifact bytes.
+    pub fn split_mut(data: &mut [u8]) -> Result<(&mut ArtifactBuffer, &mut [u8])> {
+        if data.len() < Self::DATA_OFFSET {
+            return err!(NuttooError::InvalidInput);
+        }
+        let (header, rest) = data.split_at_mut(Self::DATA_OFFSET);
+        let header: &mut ArtifactBuffer = bytemuck::from_bytes_mut(&mut header[8..]);
+        let size = header.size as usize;
+        if rest.len() < size {
+            return err!(NuttooError::InvalidInput);
+        }
+        Ok((header, &mut rest[..size]))
+    }
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+pub struct Attester {
+    pub registry: Pubkey,
+    pub attester: Pubkey,
+    pub added_at: i64,
+    pub bump: u8,
+}
+
+impl Attester {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + DEFAULT_BUMP_PADDING;
+}
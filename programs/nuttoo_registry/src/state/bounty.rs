@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::Run;
+
+/// Escrowed reward for running `module` at `version` on `inputs_sha256`.
+/// Holds the reward in lamports, or in its associated token account for `mint`.
+#[account]
+pub struct Bounty {
+    pub registry: Pubkey,
+    pub bounty_id: [u8; 32],
+    pub poster: Pubkey,
+    pub module: Pubkey,
+    pub version: String,
+    pub inputs_sha256: [u8; 32],
+    pub mint: Option<Pubkey>,
+    pub reward: u64,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl Bounty {
+    pub const LEN: usize =
+        8 + 32 + 32 + 32 + 32 + (4 + MAX_VERSION_LEN) + 32 + (1 + 32) + 8 + 8 + 8 + 1 + DEFAULT_BUMP_PADDING;
+
+    /// A successful, undisputed run by a registered worker on the bounty's
+    /// module version and inputs, recorded while the bounty was open.
+    pub fn is_satisfied_by(&self, run: &Run) -> bool {
+        run.ok
+            && !run.disputed
+            && run.worker != Pubkey::default()
+            && run.module == self.module
+            && run.module_version == self.version
+            && run.inputs_sha256 == self.inputs_sha256
+            && run.created_at >= self.created_at
+            && run.created_at <= self.expires_at
+    }
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+pub struct Category {
+    pub registry: Pubkey,
+    pub slug: String,
+    pub description: String,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Category {
+    pub fn space_for(slug_len: usize, description_len: usize) -> usize {
+        8 + 32 + (4 + slug_len) + (4 + description_len) + 8 + 1 + DEFAULT_BUMP_PADDING
+    }
+}
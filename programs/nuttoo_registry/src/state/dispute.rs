@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DisputeVerdict {
+    OriginalCorrect,
+    ChallengeCorrect,
+    Inconclusive,
+}
+
+#[account]
+pub struct RunDispute {
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub original_run: Pubkey,
+    pub challenge_run: Pubkey,
+    pub inputs_sha256: [u8; 32],
+    pub challenger: Pubkey,
+    pub created_at: i64,
+    /// Set once resolved; `None` while the dispute is open.
+    pub verdict: Option<DisputeVerdict>,
+    pub resolved_at: i64,
+    pub votes_original: u8,
+    pub votes_challenge: u8,
+    pub votes_inconclusive: u8,
+    pub voters: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl RunDispute {
+    pub const LEN: usize =
+        8 + // disc
+        32 + 32 + 32 + 32 + // registry, module, runs
+        32 + // inputs hash
+        32 + // challenger
+        8 + // created_at
+        (1 + 1) + 8 + // resolution
+        1 + 1 + 1 + // votes
+        (4 + 32 * MAX_DISPUTE_VOTERS) + // voters
+        1 + // bump
+        DEFAULT_BUMP_PADDING;
+
+    pub fn is_resolved(&self) -> bool {
+        self.verdict.is_some()
+    }
+
+    /// Records an attester vote and returns the verdict once it reaches `quorum`.
+    pub fn tally(&mut self, voter: Pubkey, verdict: DisputeVerdict, quorum: u8) -> Option<DisputeVerdict> {
+        self.voters.push(voter);
+        let count = match verdict {
+            DisputeVerdict::OriginalCorrect => {
+                self.votes_original = self.votes_original.saturating_add(1);
+                self.votes_original
+            }
+            DisputeVerdict::ChallengeCorrect => {
+                self.votes_challenge = self.votes_challenge.saturating_add(1);
+                self.votes_challenge
+            }
+            DisputeVerdict::Inconclusive => {
+                self.votes_inconclusive = self.votes_inconclusive.saturating_add(1);
+                self.votes_inconclusive
+            }
+        };
+        if quorum > 0 && count >= quorum {
+            Some(verdict)
+        } else {
+            None
+        }
+    }
+}
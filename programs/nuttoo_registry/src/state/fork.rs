@@ -8,12 +8,15 @@ pub struct Fork {
     pub module: Pubkey,
     pub owner: Pubkey,
     pub created_at: i64,
+    pub version: String,
     pub notes: String,
+    /// Module this fork was promoted to; default until promoted, at most once.
+    pub promoted_to: Pubkey,
     pub bump: u8,
 }
 
 impl Fork {
     pub fn space_for(notes_len: usize) -> usize {
-        8 + 32 + 32 + 32 + 32 + 8 + (4 + notes_len) + 1 + DEFAULT_BUMP_PADDING
+        8 + 32 + 32 + 32 + 32 + 8 + (4 + MAX_VERSION_LEN) + (4 + notes_len) + 32 + 1 + DEFAULT_BUMP_PADDING
     }
 }
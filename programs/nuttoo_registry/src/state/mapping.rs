@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// Distributes production tokens 1:1 against a test-token snapshot committed
+/// as a Merkle root over `utils::hash::mapping_leaf(index, holder, amount)`.
+/// Tokens are held in the distributor's associated token account for `mint`.
+#[account]
+pub struct MappingDistributor {
+    pub registry: Pubkey,
+    pub mint: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub snapshot_slot: u64,
+    pub num_leaves: u32,
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub claimed_count: u32,
+    pub created_at: i64,
+    /// Holders refused at claim time even if present in the tree.
+    pub denylist: Vec<Pubkey>,
+    /// One bit per leaf index, set once that leaf is claimed.
+    pub claimed_bitmap: Vec<u8>,
+    pub bump: u8,
+}
+
+impl MappingDistributor {
+    pub fn space_for(num_leaves: u32) -> usize {
+        8 + 32 + 32 + 32 + 8 + 4 + 8 + 8 + 4 + 8 +
+        (4 + MAX_MAPPING_DENYLIST * 32) + // denylist, reserved at max
+        (4 + Self::bitmap_len(num_leaves)) +
+        1 + DEFAULT_BUMP_PADDING
+    }
+
+    pub fn bitmap_len(num_leaves: u32) -> usize {
+        (num_leaves as usize).div_ceil(8)
+    }
+
+    pub fn is_claimed(&self, index: u32) -> bool {
+        self.claimed_bitmap[index as usize / 8] & (1 << (index % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u32) {
+        self.claimed_bitmap[index as usize / 8] |= 1 << (index % 8);
+    }
+
+    pub fn is_denylisted(&self, holder: &Pubkey) -> bool {
+        self.denylist.contains(holder)
+    }
+}
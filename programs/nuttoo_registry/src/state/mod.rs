@@ -2,8 +2,46 @@ pub mod registry;
 pub mod module;
 pub mod fork;
 pub mod run;
+pub mod attester;
+pub mod dispute;
+pub mod window;
+pub mod worker;
+pub mod run_log;
+pub mod run_batch;
+pub mod artifact_buffer;
+pub mod version;
+pub mod artifact;
+pub mod category;
+pub mod name_record;
+pub mod treasury;
+pub mod royalty;
+pub mod bounty;
+pub mod stake;
+pub mod mapping;
+pub mod reward;
+pub mod sponsorship;
+pub mod review;
 
 pub use registry::*;
 pub use module::*;
 pub use fork::*;
 pub use run::*;
+pub use attester::*;
+pub use dispute::*;
+pub use window::*;
+pub use worker::*;
+pub use run_log::*;
+pub use run_batch::*;
+pub use artifact_buffer::*;
+pub use version::*;
+pub use artifact::*;
+pub use category::*;
+pub use name_record::*;
+pub use treasury::*;
+pub use royalty::*;
+pub use bounty::*;
+pub use stake::*;
+pub use mapping::*;
+pub use reward::*;
+pub use sponsorship::*;
+pub use review::*;
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::state::{Policy, RateWindow, ReputationWeights, Review};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct ModuleMeta {
@@ -9,6 +11,63 @@ pub struct ModuleMeta {
     pub uri: String,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ModuleStatus {
+    #[default]
+    Active,
+    Quarantined,
+    /// Withdrawn by the registry; no longer counts towards its publisher's stake lock.
+    Retired,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct RunStats {
+    pub ok_count: u64,
+    pub fail_count: u64,
+    pub last_run_at: i64,
+    pub last_ok_at: i64,
+    pub consecutive_failures: u32,
+    pub success_ratio_bps: u16,
+    /// Exit code histogram: `0`, `1`, `2..=127`, anything else.
+    pub exit_code_buckets: [u64; 4],
+}
+
+impl RunStats {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 4 + 2 + 8 * 4;
+
+    pub fn record(&mut self, ok: bool, exit_code: i32, now: i64) {
+        let first = self.ok_count == 0 && self.fail_count == 0;
+        let sample: u32 = if ok { BPS_DENOMINATOR as u32 } else { 0 };
+
+        if ok {
+            self.ok_count = self.ok_count.saturating_add(1);
+            self.last_ok_at = now;
+            self.consecutive_failures = 0;
+        } else {
+            self.fail_count = self.fail_count.saturating_add(1);
+            self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        }
+        self.last_run_at = now;
+
+        // Exponential moving average over roughly the last RUN_STATS_WINDOW runs.
+        self.success_ratio_bps = if first {
+            sample as u16
+        } else {
+            let prev = self.success_ratio_bps as u32;
+            let window = RUN_STATS_WINDOW as u32;
+            ((prev * (window - 1) + sample) / window) as u16
+        };
+
+        let bucket = match exit_code {
+            0 => 0,
+            1 => 1,
+            2..=127 => 2,
+            _ => 3,
+        };
+        self.exit_code_buckets[bucket] = self.exit_code_buckets[bucket].saturating_add(1);
+    }
+}
+
 #[account]
 pub struct Module {
     pub registry: Pubkey,
@@ -22,6 +81,28 @@ pub struct Module {
     pub artifact_size: u64,
     pub verified: bool,
     pub run_count: u64,
+    pub fork_count: u64,
+    pub status: ModuleStatus,
+    pub run_stats: RunStats,
+    pub run_window: RateWindow,
+    pub tags: Vec<String>,
+    pub category: Pubkey,
+    /// Upstream module this one was promoted from via a fork; default for roots.
+    pub parent: Pubkey,
+    pub source_fork: Pubkey,
+    pub lineage_depth: u16,
+    /// Share of run fees routed to this module when charged on a descendant.
+    pub royalty_bps: u16,
+    /// Module-level and per-artifact attestations currently verified; revoking one takes it back.
+    pub attestation_count: u32,
+    /// Score from `ReputationWeights` as of `reputation_updated_at`.
+    pub reputation: u64,
+    pub reputation_updated_at: i64,
+    /// Review aggregates across all versions; see `average_rating_x100`.
+    pub review_count: u32,
+    pub rating_total: u64,
+    pub broken_reports: u32,
+    pub insecure_reports: u32,
     pub bump: u8,
 }
 
@@ -41,7 +122,277 @@ impl Module {
         8 + // artifact size
         1 + // verified
         8 + // run_count
+        8 + // fork_count
+        1 + // status
+        RunStats::LEN + // run_stats
+        RateWindow::LEN + // run_window
+        (4 + MAX_TAGS * (4 + MAX_TAG_LEN)) + // tags, reserved at max
+        32 + // category
+        32 + 32 + 2 + // parent, source_fork, lineage_depth
+        2 + // royalty_bps
+        4 + 8 + 8 + // attestation_count, reputation, reputation_updated_at
+        4 + 8 + 4 + 4 + // review aggregates
         1 + // bump
         DEFAULT_BUMP_PADDING
     }
+
+    /// Rejects quarantined and retired modules; they take no new runs, forks or bounties.
+    pub fn require_runnable(&self) -> Result<()> {
+        match self.status {
+            ModuleStatus::Quarantined => err!(NuttooError::ModuleQuarantined),
+            ModuleStatus::Retired => err!(NuttooError::ModuleRetired),
+            _ => Ok(()),
+        }
+    }
+
+    /// Enforces the lifetime and windowed run limits, consuming one slot.
+    pub fn consume_run_quota(&mut self, policy: &Policy, now: i64) -> Result<()> {
+        self.consume_run_quota_n(policy, now, 1)
+    }
+
+    /// Like `consume_run_quota` for `count` runs committed together.
+    pub fn consume_run_quota_n(&mut self, policy: &Policy, now: i64, count: u32) -> Result<()> {
+        let max = policy.max_runs_per_module;
+        if max > 0 && self.run_count.saturating_add(count as u64) > max {
+            return err!(NuttooError::RunLimitReached);
+        }
+        if !self.run_window.try_consume_n(now, policy.run_window_secs, policy.max_module_runs_per_window, count) {
+            return err!(NuttooError::RunRateLimited);
+        }
+        Ok(())
+    }
+
+    /// Recomputes `reputation` from the current counters; quarantined and
+    /// retired modules score zero. Returns the previous score.
+    pub fn refresh_reputation(&mut self, w: &ReputationWeights, now: i64) -> u64 {
+        let previous = self.reputation;
+        self.reputation = if self.status == ModuleStatus::Active {
+            let mut age_days = (now.saturating_sub(self.created_at).max(0) / 86_400) as u64;
+            if w.max_age_days > 0 {
+                age_days = age_days.min(w.max_age_days as u64);
+            }
+            self.run_stats.ok_count.saturating_mul(w.ok_run as u64)
+                .saturating_add(self.fork_count.saturating_mul(w.fork as u64))
+                .saturating_add((self.attestation_count as u64).saturating_mul(w.attestation as u64))
+                .saturating_add(age_days.saturating_mul(w.age_day as u64))
+                .saturating_sub(self.run_stats.fail_count.saturating_mul(w.failed_run as u64))
+        } else {
+            0
+        };
+        self.reputation_updated_at = now;
+        previous
+    }
+
+    /// Counts an attestation moving from `previous_verified` to `verified`;
+    /// re-attesting without a change leaves `attestation_count` alone.
+    pub fn record_attestation(&mut self, previous_verified: bool, verified: bool, w: &ReputationWeights, now: i64) {
+        match (previous_verified, verified) {
+            (false, true) => self.attestation_count = self.attestation_count.saturating_add(1),
+            (true, false) => self.attestation_count = self.attestation_count.saturating_sub(1),
+            _ => {}
+        }
+        self.refresh_reputation(w, now);
+    }
+
+    /// Mean review rating scaled by 100 (e.g. 450 for 4.5); 0 when unreviewed.
+    pub fn average_rating_x100(&self) -> u16 {
+        if self.review_count == 0 {
+            return 0;
+        }
+        (self.rating_total.saturating_mul(100) / self.review_count as u64) as u16
+    }
+
+    pub fn add_review(&mut self, rating: u8, flags: u8) {
+        self.review_count = self.review_count.saturating_add(1);
+        self.rating_total = self.rating_total.saturating_add(rating as u64);
+        self.broken_reports = self.broken_reports.saturating_add((flags & Review::FLAG_BROKEN != 0) as u32);
+        self.insecure_reports = self.insecure_reports.saturating_add((flags & Review::FLAG_INSECURE != 0) as u32);
+    }
+
+    pub fn remove_review(&mut self, rating: u8, flags: u8) {
+        self.review_count = self.review_count.saturating_sub(1);
+        self.rating_total = self.rating_total.saturating_sub(rating as u64);
+        self.broken_reports = self.broken_reports.saturating_sub((flags & Review::FLAG_BROKEN != 0) as u32);
+        self.insecure_reports = self.insecure_reports.saturating_sub((flags & Review::FLAG_INSECURE != 0) as u32);
+    }
+
+    /// Folds a run outcome into `run_stats` and the reputation score,
+    /// quarantining the module once `policy.quarantine_after_failures`
+    /// consecutive failures have been seen (0 disables).
+    /// Returns true if this call moved the module into quarantine.
+    pub fn apply_run(&mut self, ok: bool, exit_code: i32, now: i64, policy: &Policy) -> bool {
+        self.run_stats.record(ok, exit_code, now);
+        let quarantine_after = policy.quarantine_after_failures;
+        let quarantined = quarantine_after > 0
+            && self.status == ModuleStatus::Active
+            && self.run_stats.consecutive_failures >= quarantine_after;
+        if quarantined {
+            self.status = ModuleStatus::Quarantined;
+        }
+        self.refresh_reputation(&policy.reputation, now);
+        quarantined
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_stats_record() {
+        let mut s = RunStats::default();
+        s.record(true, 0, 10);
+        assert_eq!((s.ok_count, s.fail_count), (1, 0));
+        assert_eq!(s.success_ratio_bps, 10_000);
+        assert_eq!((s.last_run_at, s.last_ok_at), (10, 10));
+
+        s.record(false, 1, 20);
+        s.record(false, 300, 30);
+        assert_eq!((s.ok_count, s.fail_count, s.consecutive_failures), (1, 2, 2));
+        assert_eq!((s.last_run_at, s.last_ok_at), (30, 10));
+        // 10_000 * 31/32 = 9_687, then 9_687 * 31/32 = 9_384.
+        assert_eq!(s.success_ratio_bps, 9_384);
+
+        s.record(true, 0, 40);
+        s.record(false, -1, 50);
+        s.record(false, 64, 60);
+        assert_eq!(s.consecutive_failures, 2);
+        assert_eq!(s.exit_code_buckets, [2, 1, 1, 2]);
+    }
+
+    #[test]
+    fn run_stats_first_failure() {
+        let mut s = RunStats::default();
+        s.record(false, 2, 5);
+        assert_eq!(s.success_ratio_bps, 0);
+        assert_eq!(s.last_ok_at, 0);
+        assert_eq!(s.exit_code_buckets, [0, 0, 1, 0]);
+    }
+
+    fn module() -> Module {
+        Module {
+            registry: Pubkey::default(),
+            module_id: [0; 32],
+            owner: Pubkey::default(),
+            created_at: 0,
+            updated_at: 0,
+            meta: ModuleMeta::default(),
+            manifest_sha256: [0; 32],
+            artifact_sha256: [0; 32],
+            artifact_size: 0,
+            verified: false,
+            run_count: 0,
+            fork_count: 0,
+            status: ModuleStatus::Active,
+            run_stats: RunStats::default(),
+            run_window: RateWindow::default(),
+            tags: Vec::new(),
+            category: Pubkey::default(),
+            parent: Pubkey::default(),
+            source_fork: Pubkey::default(),
+            lineage_depth: 0,
+            royalty_bps: 0,
+            attestation_count: 0,
+            reputation: 0,
+            reputation_updated_at: 0,
+            review_count: 0,
+            rating_total: 0,
+            broken_reports: 0,
+            insecure_reports: 0,
+            bump: 0,
+        }
+    }
+
+    const WEIGHTS: ReputationWeights = ReputationWeights {
+        ok_run: 10,
+        failed_run: 25,
+        fork: 50,
+        attestation: 100,
+        age_day: 1,
+        max_age_days: 30,
+    };
+
+    #[test]
+    fn refresh_reputation_weights_counters() {
+        let mut m = module();
+        m.run_stats.ok_count = 3;
+        m.fork_count = 2;
+        m.attestation_count = 1;
+        let day = 86_400;
+        assert_eq!(m.refresh_reputation(&WEIGHTS, 5 * day), 0);
+        assert_eq!(m.reputation, 30 + 100 + 100 + 5);
+        assert_eq!(m.reputation_updated_at, 5 * day);
+
+        // Age is capped, failures subtract and the score saturates at zero.
+        assert_eq!(m.refresh_reputation(&WEIGHTS, 400 * day), 235);
+        assert_eq!(m.reputation, 30 + 100 + 100 + 30);
+        m.run_stats.fail_count = 100;
+        m.refresh_reputation(&WEIGHTS, 400 * day);
+        assert_eq!(m.reputation, 0);
+    }
+
+    #[test]
+    fn inactive_modules_score_zero() {
+        let mut m = module();
+        m.run_stats.ok_count = 10;
+        m.status = ModuleStatus::Quarantined;
+        m.refresh_reputation(&WEIGHTS, 0);
+        assert_eq!(m.reputation, 0);
+        m.status = ModuleStatus::Retired;
+        m.refresh_reputation(&WEIGHTS, 0);
+        assert_eq!(m.reputation, 0);
+        m.status = ModuleStatus::Active;
+        m.refresh_reputation(&WEIGHTS, 0);
+        assert_eq!(m.reputation, 100);
+        assert_eq!(module().refresh_reputation(&ReputationWeights::default(), 0), 0);
+    }
+
+    #[test]
+    fn attestations_count_transitions_only() {
+        let mut m = module();
+        m.record_attestation(false, true, &WEIGHTS, 0);
+        m.record_attestation(true, true, &WEIGHTS, 0);
+        assert_eq!((m.attestation_count, m.reputation), (1, 100));
+        m.record_attestation(true, false, &WEIGHTS, 0);
+        m.record_attestation(false, false, &WEIGHTS, 0);
+        assert_eq!((m.attestation_count, m.reputation), (0, 0));
+    }
+
+    #[test]
+    fn only_active_modules_take_runs() {
+        let mut m = module();
+        assert!(m.require_runnable().is_ok());
+        m.status = ModuleStatus::Quarantined;
+        assert_eq!(m.require_runnable(), Err(NuttooError::ModuleQuarantined.into()));
+        m.status = ModuleStatus::Retired;
+        assert_eq!(m.require_runnable(), Err(NuttooError::ModuleRetired.into()));
+    }
+
+    #[test]
+    fn review_aggregates() {
+        let mut m = module();
+        assert_eq!(m.average_rating_x100(), 0);
+        m.add_review(5, Review::FLAG_WORKS);
+        m.add_review(2, Review::FLAG_BROKEN | Review::FLAG_INSECURE);
+        m.add_review(2, Review::FLAG_BROKEN);
+        assert_eq!((m.review_count, m.rating_total), (3, 9));
+        assert_eq!((m.broken_reports, m.insecure_reports), (2, 1));
+        assert_eq!(m.average_rating_x100(), 300);
+
+        // A re-review swaps the old rating and flags for the new ones.
+        m.remove_review(2, Review::FLAG_BROKEN);
+        m.add_review(4, Review::FLAG_WORKS);
+        assert_eq!((m.review_count, m.rating_total), (3, 11));
+        assert_eq!((m.broken_reports, m.insecure_reports), (1, 1));
+        // 1100 / 3, rounded down.
+        assert_eq!(m.average_rating_x100(), 366);
+
+        for (rating, flags) in [(5, Review::FLAG_WORKS), (2, Review::FLAG_BROKEN | Review::FLAG_INSECURE), (4, Review::FLAG_WORKS)] {
+            m.remove_review(rating, flags);
+        }
+        assert_eq!((m.review_count, m.rating_total, m.broken_reports, m.insecure_reports), (0, 0, 0, 0));
+        assert_eq!(m.average_rating_x100(), 0);
+        m.remove_review(3, Review::FLAG_BROKEN);
+        assert_eq!((m.review_count, m.rating_total, m.broken_reports), (0, 0, 0));
+    }
 }
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// Claims a normalized module name within a registry.
+#[account]
+pub struct NameRecord {
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub name: String,
+    pub bump: u8,
+}
+
+impl NameRecord {
+    pub const LEN: usize = 8 + 32 + 32 + (4 + MAX_NAME_LEN) + 1 + DEFAULT_BUMP_PADDING;
+}
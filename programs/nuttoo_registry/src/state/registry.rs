@@ -1,12 +1,82 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
+use crate::errors::NuttooError;
+
+/// Integer weights folded into `Module::reputation`; all zero disables scoring.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct ReputationWeights {
+    pub ok_run: u32,
+    /// Subtracted per failed run, saturating at zero.
+    pub failed_run: u32,
+    pub fork: u32,
+    pub attestation: u32,
+    pub age_day: u32,
+    /// Cap on the days of age that count; 0 leaves age uncapped.
+    pub max_age_days: u32,
+}
+
+impl ReputationWeights {
+    pub const LEN: usize = 4 * 6;
+}
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
 pub struct Policy {
     pub allow_forks: bool,
     pub allow_unverified: bool,
     pub max_artifact_bytes: u64,
+    /// Lifetime cap on runs per module; 0 disables it.
     pub max_runs_per_module: u64,
+    pub dispute_quorum: u8,
+    pub quarantine_after_failures: u32,
+    /// Length of the sliding run-rate window; 0 disables windowed limits.
+    pub run_window_secs: u32,
+    pub max_module_runs_per_window: u32,
+    pub max_worker_runs_per_window: u32,
+    /// Require `meta.uri` to embed a digest equal to `artifact_sha256`.
+    pub require_content_addressed_uri: bool,
+    /// Cap on the summed size of all artifacts of one module version; 0 disables it.
+    pub max_version_artifact_bytes: u64,
+    /// Require module, fork and run ids to match their `utils::hash::derive_*` values.
+    pub derived_ids: bool,
+    pub publish_fee_lamports: u64,
+    pub fork_fee_lamports: u64,
+    pub run_fee_lamports: u64,
+    /// When set, the fee fields above are charged in base units of this SPL mint instead of lamports.
+    pub fee_mint: Option<Pubkey>,
+    /// Upper bound on `Module::royalty_bps`.
+    pub max_royalty_bps: u16,
+    /// Number of upstream modules paid a royalty on run fees; 0 disables royalties.
+    pub royalty_depth: u8,
+    /// Share kept at each further level up the lineage, in basis points.
+    pub royalty_decay_bps: u16,
+    /// Lamports a publisher must keep staked while they have live modules; 0 disables staking.
+    pub min_publish_stake: u64,
+    pub unstake_cooldown_secs: u32,
+    pub reputation: ReputationWeights,
+    /// Only accept reviews backed by a run of the reviewed version owned or executed by the reviewer.
+    pub review_requires_run: bool,
+}
+
+impl Policy {
+    /// Rejects parameter combinations that would leave the registry stuck.
+    pub fn validate(&self) -> Result<()> {
+        // A zero quorum never resolves and one above the voter cap is unreachable.
+        if self.dispute_quorum == 0 || self.dispute_quorum as usize > MAX_DISPUTE_VOTERS {
+            return err!(NuttooError::InvalidPolicy);
+        }
+        // Royalties are only split out of lamport fees.
+        if self.fee_mint.is_some() && self.royalty_depth > 0 {
+            return err!(NuttooError::InvalidPolicy);
+        }
+        Ok(())
+    }
+
+    /// Whether runs must name a registered worker so its window can be enforced.
+    pub fn limits_workers(&self) -> bool {
+        self.run_window_secs > 0 && self.max_worker_runs_per_window > 0
+    }
+
+    pub const LEN: usize = 1 + 1 + 8 + 8 + 1 + 4 + 4 + 4 + 4 + 1 + 8 + 1 + 8 + 8 + 8 + 1 + 32 + 2 + 1 + 2 + 8 + 4 + ReputationWeights::LEN + 1;
 }
 
 #[account]
@@ -18,6 +88,10 @@ pub struct Registry {
     pub module_count: u64,
     pub fork_count: u64,
     pub run_count: u64,
+    pub event_seq: u64,
+    pub epoch_count: u64,
+    /// Reward epoch currently accruing usage points; default when none.
+    pub active_epoch: Pubkey,
     pub bump: u8,
 }
 
@@ -26,12 +100,21 @@ impl Registry {
         8 + // disc
         32 + // authority
         8 + 8 + // timestamps
-        (1 + 1 + 8 + 8) + // policy
+        Policy::LEN + // policy
         8 + 8 + 8 + // counts
+        8 + // event_seq
+        8 + 32 + // epoch_count, active_epoch
         1 + // bump
         DEFAULT_BUMP_PADDING;
 
     pub fn touch(&mut self, now: i64) {
         self.updated_at = now;
     }
+
+    /// Returns the sequence number for the next emitted event and advances it.
+    pub fn next_event_seq(&mut self) -> u64 {
+        let seq = self.event_seq;
+        self.event_seq = self.event_seq.saturating_add(1);
+        seq
+    }
 }
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+pub struct Review {
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub module_version: Pubkey,
+    pub reviewer: Pubkey,
+    /// 1..=5.
+    pub rating: u8,
+    /// Bitwise OR of the `FLAG_*` constants.
+    pub flags: u8,
+    /// Hash of the off-chain comment body; zero when there is none.
+    pub comment_sha256: [u8; 32],
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl Review {
+    pub const LEN: usize =
+        8 + // disc
+        32 + 32 + 32 + 32 + // registry, module, module_version, reviewer
+        1 + 1 + // rating, flags
+        32 + // comment hash
+        8 + 8 + // timestamps
+        1 + // bump
+        DEFAULT_BUMP_PADDING;
+
+    pub const FLAG_WORKS: u8 = 1 << 0;
+    pub const FLAG_BROKEN: u8 = 1 << 1;
+    pub const FLAG_INSECURE: u8 = 1 << 2;
+    pub const FLAGS_MASK: u8 = Self::FLAG_WORKS | Self::FLAG_BROKEN | Self::FLAG_INSECURE;
+
+    /// Whether this is a fresh account that has not recorded a review yet.
+    pub fn is_new(&self) -> bool {
+        self.rating == 0
+    }
+}
@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+
+/// A funded reward pool split pro rata over module usage points accrued
+/// between `start_at` and `end_at`. Holds the pool lamports itself.
+#[account]
+pub struct RewardEpoch {
+    pub registry: Pubkey,
+    pub index: u64,
+    pub start_at: i64,
+    pub end_at: i64,
+    pub pool: u64,
+    pub run_points: u32,
+    pub fork_points: u32,
+    pub total_points: u64,
+    pub claimed_amount: u64,
+    /// Claims are refused from here on and the remaining pool may be swept.
+    pub claim_deadline: i64,
+    pub bump: u8,
+}
+
+impl RewardEpoch {
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 4 + 4 + 8 + 8 + 8 + 1 + DEFAULT_BUMP_PADDING;
+
+    pub fn is_open(&self, now: i64) -> bool {
+        now >= self.start_at && now < self.end_at
+    }
+
+    /// Whether unclaimed pool lamports may be swept back to the authority:
+    /// after the claim deadline, or as soon as the epoch ends without points.
+    pub fn can_sweep(&self, now: i64) -> bool {
+        now >= self.claim_deadline || (now >= self.end_at && self.total_points == 0)
+    }
+
+    /// Pool share owed for `points` once the epoch has closed.
+    pub fn reward_for(&self, points: u64) -> u64 {
+        if self.total_points == 0 {
+            return 0;
+        }
+        (self.pool as u128 * points as u128 / self.total_points as u128) as u64
+    }
+}
+
+/// Usage points of one module within one reward epoch.
+#[account]
+pub struct ModuleUsage {
+    pub epoch: Pubkey,
+    pub module: Pubkey,
+    pub points: u64,
+    pub claimed: bool,
+    pub bump: u8,
+}
+
+impl ModuleUsage {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 1 + DEFAULT_BUMP_PADDING;
+}
+
+/// Which kind of usage is being credited.
+#[derive(Clone, Copy)]
+pub enum UsageKind {
+    Run,
+    Fork,
+}
+
+/// Credits `module` with usage points in the registry's active epoch. The
+/// epoch account is required while `active_epoch` is set and the usage
+/// account while that epoch is open. The first call after the epoch ends
+/// clears `active_epoch`, so later runs and forks need neither account.
+pub fn accrue_usage(
+    active_epoch: &mut Pubkey,
+    epoch: Option<&mut Account<RewardEpoch>>,
+    usage: Option<&mut Account<ModuleUsage>>,
+    usage_bump: u8,
+    module: Pubkey,
+    kind: UsageKind,
+    now: i64,
+) -> Result<()> {
+    if *active_epoch == Pubkey::default() {
+        return Ok(());
+    }
+    let Some(e) = epoch else {
+        return err!(NuttooError::InvalidInput);
+    };
+    require_keys_eq!(e.key(), *active_epoch, NuttooError::InvalidInput);
+
+    if now >= e.end_at {
+        // Fail rather than let `init_if_needed` create a usage account that can never accrue.
+        if usage.is_some() {
+            return err!(NuttooError::EpochClosed);
+        }
+        *active_epoch = Pubkey::default();
+        return Ok(());
+    }
+    let Some(u) = usage else {
+        return err!(NuttooError::InvalidInput);
+    };
+
+    if u.epoch == Pubkey::default() {
+        u.epoch = e.key();
+        u.module = module;
+        u.bump = usage_bump;
+    }
+    require_keys_eq!(u.module, module, NuttooError::InvalidInput);
+
+    let points = match kind {
+        UsageKind::Run => e.run_points,
+        UsageKind::Fork => e.fork_points,
+    } as u64;
+    if points == 0 || !e.is_open(now) {
+        return Ok(());
+    }
+    u.points = u.points.saturating_add(points);
+    e.total_points = e.total_points.saturating_add(points);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn epoch(pool: u64, total_points: u64) -> RewardEpoch {
+        RewardEpoch {
+            registry: Pubkey::default(),
+            index: 0,
+            start_at: 100,
+            end_at: 200,
+            pool,
+            run_points: 1,
+            fork_points: 5,
+            total_points,
+            claimed_amount: 0,
+            claim_deadline: 300,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn reward_for_is_pro_rata() {
+        let e = epoch(1_000, 3);
+        assert_eq!(e.reward_for(1), 333);
+        assert_eq!(e.reward_for(2), 666);
+        // Rounding leaves dust behind for the sweep.
+        assert_eq!(e.pool - e.reward_for(1) - e.reward_for(2), 1);
+        assert_eq!(e.reward_for(3), 1_000);
+        assert_eq!(epoch(1_000, 0).reward_for(5), 0);
+        assert_eq!(epoch(u64::MAX, u64::MAX).reward_for(u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn open_and_sweep_windows() {
+        let e = epoch(1_000, 3);
+        assert!(!e.is_open(99));
+        assert!(e.is_open(100) && e.is_open(199));
+        assert!(!e.is_open(200));
+        assert!(!e.can_sweep(299));
+        assert!(e.can_sweep(300));
+        // Nothing to claim: the pool may be swept as soon as the epoch ends.
+        assert!(!epoch(1_000, 0).can_sweep(199));
+        assert!(epoch(1_000, 0).can_sweep(200));
+    }
+}
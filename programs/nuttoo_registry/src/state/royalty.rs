@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// Royalties owed to a module's owner; the accrued lamports are held by this account.
+#[account]
+pub struct RoyaltyBalance {
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub accrued: u64,
+    pub claimed: u64,
+    pub bump: u8,
+}
+
+impl RoyaltyBalance {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 1 + DEFAULT_BUMP_PADDING;
+
+    pub fn claimable(&self) -> u64 {
+        self.accrued.saturating_sub(self.claimed)
+    }
+}
+
+/// Royalty owed to the ancestor `level` steps up (1 = parent) on a fee of `fee`.
+pub fn royalty_share(fee: u64, royalty_bps: u16, decay_bps: u16, level: u8) -> u64 {
+    let bps = BPS_DENOMINATOR as u128;
+    let mut share = fee as u128 * royalty_bps as u128 / bps;
+    for _ in 1..level {
+        share = share * decay_bps as u128 / bps;
+    }
+    share as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn royalty_share_decays_per_level() {
+        // 5% royalty, each further ancestor gets half of the level below.
+        assert_eq!(royalty_share(1_000_000, 500, 5_000, 1), 50_000);
+        assert_eq!(royalty_share(1_000_000, 500, 5_000, 2), 25_000);
+        assert_eq!(royalty_share(1_000_000, 500, 5_000, 3), 12_500);
+        assert_eq!(royalty_share(1_000_000, 500, 0, 2), 0);
+        assert_eq!(royalty_share(1_000_000, 0, 5_000, 1), 0);
+    }
+
+    #[test]
+    fn royalty_share_rounds_down_without_overflow() {
+        assert_eq!(royalty_share(199, 100, 10_000, 1), 1);
+        assert_eq!(royalty_share(u64::MAX, 10_000, 10_000, 4), u64::MAX);
+    }
+}
@@ -1,6 +1,16 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RuntimeKind {
+    #[default]
+    Node,
+    Wasm,
+    Python,
+    Native,
+    Other,
+}
+
 #[account]
 pub struct Run {
     pub registry: Pubkey,
@@ -8,15 +18,34 @@ pub struct Run {
     pub module: Pubkey,
     pub fork: Pubkey,
     pub owner: Pubkey,
+    pub worker: Pubkey,
     pub created_at: i64,
     pub ok: bool,
     pub exit_code: i32,
     pub stdout_sha256: [u8; 32],
     pub stderr_sha256: [u8; 32],
+    pub inputs_sha256: [u8; 32],
+    pub outputs_sha256: [u8; 32],
+    pub started_at: i64,
+    pub finished_at: i64,
+    pub wall_time_ms: u64,
+    pub peak_memory_bytes: u64,
+    pub runtime_kind: RuntimeKind,
+    pub runtime_version: String,
+    pub module_version: String,
+    pub disputed: bool,
     pub bump: u8,
 }
 
 impl Run {
     pub const LEN: usize =
-        8 + 32 + 32 + 32 + 32 + 32 + 8 + 1 + 4 + 32 + 32 + 1 + DEFAULT_BUMP_PADDING;
+        8 + 32 + 32 + 32 + 32 + 32 + 8 + 1 + 4 + 32 + 32 +
+        32 + // worker
+        32 + 32 + // inputs / outputs hashes
+        8 + 8 + // started_at / finished_at
+        8 + 8 + // wall time / peak memory
+        1 + (4 + MAX_RUNTIME_VERSION_LEN) + // runtime
+        (4 + MAX_VERSION_LEN) + // module version
+        1 + // disputed
+        1 + DEFAULT_BUMP_PADDING;
 }
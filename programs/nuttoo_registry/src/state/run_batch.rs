@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+pub struct RunBatch {
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub batch_id: [u8; 32],
+    pub merkle_root: [u8; 32],
+    pub count: u32,
+    pub first_run_at: i64,
+    pub last_run_at: i64,
+    pub committed_at: i64,
+    pub committer: Pubkey,
+    pub bump: u8,
+}
+
+impl RunBatch {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 4 + 8 + 8 + 8 + 32 + 1 + DEFAULT_BUMP_PADDING;
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::errors::NuttooError;
+
+/// Compact, fixed-size run record stored in a `RunLog` ring buffer.
+#[zero_copy]
+#[derive(Default)]
+pub struct RunLogEntry {
+    pub inputs_sha256: [u8; 32],
+    pub outputs_sha256: [u8; 32],
+    pub recorded_at: i64,
+    pub wall_time_ms: u64,
+    pub exit_code: i32,
+    pub ok: u8,
+    pub runtime_kind: u8,
+    pub _padding: [u8; 2],
+}
+
+/// Ring buffer header; `capacity` entries follow it in the account data.
+#[account(zero_copy)]
+pub struct RunLog {
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub capacity: u32,
+    pub head: u32,
+    pub len: u32,
+    pub bump: u8,
+    pub _padding: [u8; 3],
+    pub total: u64,
+}
+
+impl RunLog {
+    pub const ENTRIES_OFFSET: usize = 8 + std::mem::size_of::<RunLog>();
+
+    pub fn space_for(capacity: u32) -> usize {
+        Self::ENTRIES_OFFSET + capacity as usize * std::mem::size_of::<RunLogEntry>()
+    }
+
+    /// Splits raw account data into the header and its entry slots.
+    pub fn split_mut(data: &mut [u8]) -> Result<(&mut RunLog, &mut [RunLogEntry])> {
+        if data.len() < Self::ENTRIES_OFFSET {
+            return err!(NuttooError::InvalidInput);
+        }
+        let (header, rest) = data.split_at_mut(Self::ENTRIES_OFFSET);
+        let header: &mut RunLog = bytemuck::from_bytes_mut(&mut header[8..]);
+        let bytes = header.capacity as usize * std::mem::size_of::<RunLogEntry>();
+        if rest.len() < bytes {
+            return err!(NuttooError::InvalidInput);
+        }
+        let entries: &mut [RunLogEntry] = bytemuck::cast_slice_mut(&mut rest[..bytes]);
+        Ok((header, entries))
+    }
+
+    /// Writes `entry` at the head, overwriting the oldest entry when full.
+    /// Returns the entry's sequence number.
+    pub fn push(&mut self, entries: &mut [RunLogEntry], entry: RunLogEntry) -> u64 {
+        entries[self.head as usize] = entry;
+        self.head = (self.head + 1) % self.capacity;
+        if self.len < self.capacity {
+            self.len += 1;
+        }
+        let seq = self.total;
+        self.total = self.total.saturating_add(1);
+        seq
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// Module-owned escrow receiving sponsorships; holds lamports itself and
+/// tokens of the registry's configured mint in its associated token account.
+#[account]
+pub struct SponsorEscrow {
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub lamports_received: u64,
+    pub tokens_received: u64,
+    pub lamports_withdrawn: u64,
+    pub tokens_withdrawn: u64,
+    pub bump: u8,
+}
+
+impl SponsorEscrow {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + DEFAULT_BUMP_PADDING;
+
+    pub fn credit(&mut self, mint: Option<Pubkey>, amount: u64) {
+        match mint {
+            Some(_) => self.tokens_received = self.tokens_received.saturating_add(amount),
+            None => self.lamports_received = self.lamports_received.saturating_add(amount),
+        }
+    }
+}
+
+/// Recurring installments prefunded into the sponsor's `Sponsorship` account.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SponsorSchedule {
+    pub mint: Option<Pubkey>,
+    pub amount_per_period: u64,
+    pub period_secs: u32,
+    pub remaining_periods: u32,
+    pub next_due_at: i64,
+}
+
+impl SponsorSchedule {
+    pub const LEN: usize = (1 + 32) + 8 + 4 + 4 + 8;
+
+    /// Installment to release out of `available` prefunded balance. The last
+    /// installment takes everything left, so transfer-fee shortfalls and stray
+    /// deposits never stay behind.
+    pub fn installment(&self, available: u64) -> u64 {
+        if self.remaining_periods <= 1 {
+            available
+        } else {
+            self.amount_per_period.min(available)
+        }
+    }
+}
+
+/// One sponsor's support for one module.
+#[account]
+pub struct Sponsorship {
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub sponsor: Pubkey,
+    pub total_lamports: u64,
+    pub total_tokens: u64,
+    pub schedule: Option<SponsorSchedule>,
+    pub bump: u8,
+}
+
+impl Sponsorship {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + (1 + SponsorSchedule::LEN) + 1 + DEFAULT_BUMP_PADDING;
+
+    pub fn credit(&mut self, mint: Option<Pubkey>, amount: u64) {
+        match mint {
+            Some(_) => self.total_tokens = self.total_tokens.saturating_add(amount),
+            None => self.total_lamports = self.total_lamports.saturating_add(amount),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::Policy;
+
+/// Lamports staked by a publisher, held by this account.
+#[account]
+pub struct Stake {
+    pub registry: Pubkey,
+    pub publisher: Pubkey,
+    pub amount: u64,
+    /// Published modules that are not retired.
+    pub live_modules: u32,
+    pub slashed_total: u64,
+    pub unstake_amount: u64,
+    pub unstake_requested_at: i64,
+    pub bump: u8,
+}
+
+impl Stake {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 4 + 8 + 8 + 8 + 1 + DEFAULT_BUMP_PADDING;
+
+    /// Stake not already queued for withdrawal.
+    pub fn bonded(&self) -> u64 {
+        self.amount.saturating_sub(self.unstake_amount)
+    }
+
+    /// Whether `bonded()` covers the policy minimum for publishing.
+    pub fn covers(&self, policy: &Policy) -> bool {
+        self.bonded() >= policy.min_publish_stake
+    }
+
+    /// Whether `amount` may leave the stake without breaking the lock held by live modules.
+    pub fn can_release(&self, policy: &Policy, amount: u64) -> bool {
+        self.live_modules == 0 || self.amount.saturating_sub(amount) >= policy.min_publish_stake
+    }
+
+    /// Loads a stake PDA that may not be initialized.
+    pub fn load_optional(info: &AccountInfo) -> Result<Option<Stake>> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(None);
+        }
+        let data = info.try_borrow_data()?;
+        Ok(Some(Stake::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Writes a stake loaded with `load_optional` back to its account.
+    pub fn store(&self, info: &AccountInfo) -> Result<()> {
+        let mut data = info.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut data[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stake(amount: u64, live_modules: u32, unstake_amount: u64) -> Stake {
+        Stake {
+            registry: Pubkey::default(),
+            publisher: Pubkey::default(),
+            amount,
+            live_modules,
+            slashed_total: 0,
+            unstake_amount,
+            unstake_requested_at: 0,
+            bump: 0,
+        }
+    }
+
+    fn policy(min_publish_stake: u64) -> Policy {
+        Policy { min_publish_stake, ..Policy::default() }
+    }
+
+    #[test]
+    fn can_release_keeps_the_lock() {
+        let p = policy(100);
+        assert!(stake(150, 1, 0).can_release(&p, 50));
+        assert!(!stake(150, 1, 0).can_release(&p, 51));
+        // Without live modules nothing is locked.
+        assert!(stake(150, 0, 0).can_release(&p, 150));
+        assert!(stake(150, 3, 0).can_release(&policy(0), 150));
+    }
+
+    #[test]
+    fn queued_unstake_is_not_bonded() {
+        let p = policy(100);
+        assert_eq!(stake(150, 1, 60).bonded(), 90);
+        assert!(!stake(150, 1, 60).covers(&p));
+        assert!(stake(150, 1, 50).covers(&p));
+    }
+}
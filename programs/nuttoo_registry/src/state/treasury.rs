@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::constants::*;
+use crate::errors::NuttooError;
+
+/// Registry-owned account collecting publish, fork and run fees.
+#[account]
+pub struct Treasury {
+    pub registry: Pubkey,
+    pub total_collected: u64,
+    pub total_withdrawn: u64,
+    pub bump: u8,
+}
+
+impl Treasury {
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 1 + DEFAULT_BUMP_PADDING;
+
+    /// Transfers `amount` lamports from `payer` into the treasury.
+    pub fn collect<'info>(
+        treasury: &mut Account<'info, Treasury>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: treasury.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        treasury.total_collected = treasury.total_collected.saturating_add(amount);
+        Ok(())
+    }
+
+    /// Lamports above the rent-exempt minimum that may be withdrawn.
+    pub fn available(info: &AccountInfo) -> Result<u64> {
+        let min = Rent::get()?.minimum_balance(info.data_len());
+        Ok(info.lamports().saturating_sub(min))
+    }
+
+    /// Moves `amount` lamports out of the program-owned treasury.
+    pub fn pay_out(treasury: &mut Account<Treasury>, to: &AccountInfo, amount: u64) -> Result<()> {
+        let info = treasury.to_account_info();
+        if amount > Self::available(&info)? {
+            return err!(NuttooError::InsufficientTreasury);
+        }
+        **info.try_borrow_mut_lamports()? -= amount;
+        **to.try_borrow_mut_lamports()? += amount;
+        treasury.total_withdrawn = treasury.total_withdrawn.saturating_add(amount);
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+pub struct ModuleVersion {
+    pub registry: Pubkey,
+    pub module: Pubkey,
+    pub version: String,
+    pub created_at: i64,
+    pub artifact_count: u16,
+    pub artifact_bytes: u64,
+    pub yanked: bool,
+    pub yanked_at: i64,
+    pub yank_reason: String,
+    pub bump: u8,
+}
+
+impl ModuleVersion {
+    pub const LEN: usize =
+        8 + // disc
+        32 + 32 + // registry, module
+        (4 + MAX_VERSION_LEN) + // version
+        8 + // created_at
+        2 + 8 + // artifact aggregate
+        1 + 8 + (4 + MAX_YANK_REASON_LEN) + // yank state
+        1 + // bump
+        DEFAULT_BUMP_PADDING;
+
+    /// Whether new consumers may resolve this version: yanked versions are
+    /// only resolvable when explicitly pinned.
+    pub fn is_resolvable(&self, pinned: bool) -> bool {
+        !self.yanked || pinned
+    }
+
+    /// Loads a version PDA that may not be initialized yet.
+    pub fn load_optional(info: &AccountInfo) -> Result<Option<ModuleVersion>> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(None);
+        }
+        let data = info.try_borrow_data()?;
+        Ok(Some(ModuleVersion::try_deserialize(&mut &data[..])?))
+    }
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+/// Two-bucket sliding window counter: the estimate weights the previous
+/// window's count by how much of it still overlaps the sliding window.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct RateWindow {
+    pub window_start: i64,
+    pub current: u32,
+    pub previous: u32,
+}
+
+impl RateWindow {
+    pub const LEN: usize = 8 + 4 + 4;
+
+    fn roll(&mut self, now: i64, window_secs: i64) {
+        let elapsed = now.saturating_sub(self.window_start);
+        if elapsed < window_secs {
+            return;
+        }
+        self.previous = if elapsed < window_secs * 2 { self.current } else { 0 };
+        self.current = 0;
+        self.window_start = now - elapsed % window_secs;
+    }
+
+    pub fn estimate(&self, now: i64, window_secs: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.window_start).clamp(0, window_secs);
+        let overlap = (window_secs - elapsed) as u64;
+        (self.previous as u64 * overlap) / window_secs as u64 + self.current as u64
+    }
+
+    /// Counts one event if the sliding estimate stays within `limit`.
+    /// A zero `window_secs` or `limit` disables the check.
+    pub fn try_consume(&mut self, now: i64, window_secs: u32, limit: u32) -> bool {
+        self.try_consume_n(now, window_secs, limit, 1)
+    }
+
+    /// Counts `n` events at once, all or nothing.
+    pub fn try_consume_n(&mut self, now: i64, window_secs: u32, limit: u32, n: u32) -> bool {
+        if window_secs == 0 || limit == 0 {
+            return true;
+        }
+        let window_secs = window_secs as i64;
+        if self.window_start == 0 {
+            self.window_start = now;
+        }
+        self.roll(now, window_secs);
+        if self.estimate(now, window_secs).saturating_add(n as u64) > limit as u64 {
+            return false;
+        }
+        self.current = self.current.saturating_add(n);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_without_window_or_limit() {
+        let mut w = RateWindow::default();
+        for _ in 0..10 {
+            assert!(w.try_consume(100, 0, 1));
+            assert!(w.try_consume(100, 60, 0));
+        }
+        assert_eq!(w.current, 0);
+    }
+
+    #[test]
+    fn limits_within_a_window() {
+        let mut w = RateWindow::default();
+        assert!(w.try_consume(1_000, 60, 2));
+        assert!(w.try_consume(1_010, 60, 2));
+        assert!(!w.try_consume(1_059, 60, 2));
+        assert_eq!(w.current, 2);
+    }
+
+    #[test]
+    fn previous_window_decays() {
+        let mut w = RateWindow::default();
+        assert!(w.try_consume(1_000, 60, 2));
+        assert!(w.try_consume(1_000, 60, 2));
+        // Half way into the next window, half of the previous count still overlaps.
+        assert!(w.try_consume(1_090, 60, 2));
+        assert!(!w.try_consume(1_090, 60, 2));
+        assert_eq!((w.window_start, w.previous, w.current), (1_060, 2, 1));
+        // Two windows later nothing carries over.
+        assert!(w.try_consume(1_200, 60, 2));
+        assert!(w.try_consume(1_200, 60, 2));
+        assert_eq!((w.window_start, w.previous, w.current), (1_180, 0, 2));
+    }
+
+    #[test]
+    fn consume_n_is_all_or_nothing() {
+        let mut w = RateWindow::default();
+        assert!(w.try_consume_n(1_000, 60, 5, 3));
+        assert!(!w.try_consume_n(1_001, 60, 5, 3));
+        assert_eq!(w.current, 3);
+        assert!(w.try_consume_n(1_001, 60, 5, 2));
+    }
+}
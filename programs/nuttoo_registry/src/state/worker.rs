@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::state::{Policy, RateWindow};
+
+#[account]
+pub struct Worker {
+    pub registry: Pubkey,
+    pub worker: Pubkey,
+    pub registered_at: i64,
+    pub active: bool,
+    pub run_count: u64,
+    pub run_window: RateWindow,
+    pub bump: u8,
+}
+
+impl Worker {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 8 + RateWindow::LEN + 1 + DEFAULT_BUMP_PADDING;
+
+    /// Counts `count` runs against `worker`'s window and returns its wallet.
+    /// A worker is required while `policy.limits_workers()`; otherwise runs
+    /// without one are attributed to the default key.
+    pub fn consume_runs(worker: Option<&mut Worker>, policy: &Policy, now: i64, count: u32) -> Result<Pubkey> {
+        let Some(w) = worker else {
+            if policy.limits_workers() {
+                return err!(NuttooError::WorkerRequired);
+            }
+            return Ok(Pubkey::default());
+        };
+        if !w.active {
+            return err!(NuttooError::WorkerInactive);
+        }
+        if !w.run_window.try_consume_n(now, policy.run_window_secs, policy.max_worker_runs_per_window, count) {
+            return err!(NuttooError::RunRateLimited);
+        }
+        w.run_count = w.run_count.saturating_add(count as u64);
+        Ok(w.worker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn worker(active: bool) -> Worker {
+        Worker {
+            registry: Pubkey::default(),
+            worker: Pubkey::new_unique(),
+            registered_at: 0,
+            active,
+            run_count: 0,
+            run_window: RateWindow::default(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn worker_required_only_while_limited() {
+        let open = Policy::default();
+        assert_eq!(Worker::consume_runs(None, &open, 0, 1), Ok(Pubkey::default()));
+        let limited = Policy { run_window_secs: 60, max_worker_runs_per_window: 2, ..Policy::default() };
+        assert_eq!(Worker::consume_runs(None, &limited, 0, 1), Err(NuttooError::WorkerRequired.into()));
+    }
+
+    #[test]
+    fn batches_count_every_run() {
+        let policy = Policy { run_window_secs: 60, max_worker_runs_per_window: 3, ..Policy::default() };
+        let mut w = worker(true);
+        assert_eq!(Worker::consume_runs(Some(&mut w), &policy, 1_000, 2), Ok(w.worker));
+        assert_eq!(Worker::consume_runs(Some(&mut w), &policy, 1_000, 2), Err(NuttooError::RunRateLimited.into()));
+        assert_eq!(Worker::consume_runs(Some(&mut w), &policy, 1_000, 1), Ok(w.worker));
+        assert_eq!(w.run_count, 3);
+
+        let mut idle = worker(false);
+        assert_eq!(Worker::consume_runs(Some(&mut idle), &policy, 1_000, 1), Err(NuttooError::WorkerInactive.into()));
+    }
+}
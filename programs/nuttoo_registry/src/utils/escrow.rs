@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token::accessor;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::errors::NuttooError;
+
+/// Token accounts for a program-owned escrow holding an SPL token instead of lamports.
+/// `vault` is the escrow account's associated token account for `mint`.
+#[derive(Accounts)]
+pub struct EscrowTokenAccounts<'info> {
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Depositor's source account, or the recipient's destination account.
+    #[account(mut)]
+    pub counterparty: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl<'info> EscrowTokenAccounts<'info> {
+    /// Mint of a token escrow, or `None` when the escrow holds lamports.
+    pub fn mint_key(&self) -> Option<Pubkey> {
+        self.mint.as_ref().map(|m| m.key())
+    }
+
+    /// Requires `counterparty` to be `owner`'s associated token account, for
+    /// permissionless payouts that must land in a canonical destination.
+    pub fn require_counterparty_ata(&self, owner: &Pubkey) -> Result<()> {
+        let (Some(m), Some(counterparty), Some(token_program)) =
+            (self.mint.as_ref(), self.counterparty.as_ref(), self.token_program.as_ref())
+        else {
+            return err!(NuttooError::InvalidFeeAccounts);
+        };
+        require_keys_eq!(
+            counterparty.key(),
+            get_associated_token_address_with_program_id(owner, &m.key(), &token_program.key()),
+            NuttooError::InvalidFeeAccounts
+        );
+        Ok(())
+    }
+
+    /// Returns the token accounts after checking them against `mint` and the escrow's ATA.
+    #[allow(clippy::type_complexity)]
+    fn checked(
+        &self,
+        escrow: &Pubkey,
+        mint: Pubkey,
+    ) -> Result<(
+        &InterfaceAccount<'info, Mint>,
+        &InterfaceAccount<'info, TokenAccount>,
+        &InterfaceAccount<'info, TokenAccount>,
+        &Interface<'info, TokenInterface>,
+    )> {
+        let (Some(m), Some(counterparty), Some(vault), Some(token_program)) =
+            (self.mint.as_ref(), self.counterparty.as_ref(), self.vault.as_ref(), self.token_program.as_ref())
+        else {
+            return err!(NuttooError::InvalidFeeAccounts);
+        };
+        require_keys_eq!(m.key(), mint, NuttooError::InvalidFeeAccounts);
+        require_keys_eq!(*m.to_account_info().owner, token_program.key(), NuttooError::InvalidFeeAccounts);
+        require_keys_eq!(
+            vault.key(),
+            get_associated_token_address_with_program_id(escrow, &mint, &token_program.key()),
+            NuttooError::InvalidFeeAccounts
+        );
+        require_keys_eq!(counterparty.mint, mint, NuttooError::InvalidFeeAccounts);
+        Ok((m, counterparty, vault, token_program))
+    }
+}
+
+/// What `escrow` can pay out right now: lamports above its rent-exempt
+/// minimum, or the whole balance of its token vault, stray deposits included.
+pub fn escrow_available<'info>(
+    escrow: &AccountInfo<'info>,
+    mint: Option<Pubkey>,
+    tokens: &EscrowTokenAccounts<'info>,
+) -> Result<u64> {
+    let Some(mint) = mint else {
+        let min = Rent::get()?.minimum_balance(escrow.data_len());
+        return Ok(escrow.lamports().saturating_sub(min));
+    };
+    let (_, _, vault, _) = tokens.checked(escrow.key, mint)?;
+    Ok(vault.amount)
+}
+
+/// Moves `amount` from `depositor` into `escrow`: lamports directly, or tokens
+/// into its vault. Returns the amount that arrived, which is less than
+/// `amount` for Token-2022 mints with a transfer fee.
+pub fn escrow_deposit<'info>(
+    escrow: &AccountInfo<'info>,
+    mint: Option<Pubkey>,
+    tokens: &EscrowTokenAccounts<'info>,
+    depositor: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    if amount == 0 {
+        return Ok(0);
+    }
+    let Some(mint) = mint else {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: depositor.clone(),
+                    to: escrow.clone(),
+                },
+            ),
+            amount,
+        )?;
+        return Ok(amount);
+    };
+
+    let (m, from, vault, token_program) = tokens.checked(escrow.key, mint)?;
+    let before = vault.amount;
+    token_interface::transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: from.to_account_info(),
+                mint: m.to_account_info(),
+                to: vault.to_account_info(),
+                authority: depositor.clone(),
+            },
+        ),
+        amount,
+        m.decimals,
+    )?;
+    Ok(accessor::amount(&vault.to_account_info())?.saturating_sub(before))
+}
+
+/// Pays `amount` out of a program-owned `escrow` to `recipient`. Lamport
+/// escrows keep their rent-exempt minimum; token escrows are signed for with
+/// `signer_seeds` and require the destination account to be owned by `recipient`.
+/// Returns the amount that arrived, net of any Token-2022 transfer fee.
+pub fn escrow_release<'info>(
+    escrow: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+    mint: Option<Pubkey>,
+    tokens: &EscrowTokenAccounts<'info>,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    if amount == 0 {
+        return Ok(0);
+    }
+    let Some(mint) = mint else {
+        let min = Rent::get()?.minimum_balance(escrow.data_len());
+        if amount > escrow.lamports().saturating_sub(min) {
+            return err!(NuttooError::InsufficientTreasury);
+        }
+        **escrow.try_borrow_mut_lamports()? -= amount;
+        **recipient.try_borrow_mut_lamports()? += amount;
+        return Ok(amount);
+    };
+
+    let (m, to, vault, token_program) = tokens.checked(escrow.key, mint)?;
+    require_keys_eq!(to.owner, recipient.key(), NuttooError::InvalidFeeAccounts);
+    let before = to.amount;
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: vault.to_account_info(),
+                mint: m.to_account_info(),
+                to: to.to_account_info(),
+                authority: escrow.clone(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+        m.decimals,
+    )?;
+    Ok(accessor::amount(&to.to_account_info())?.saturating_sub(before))
+}
+
+/// Closes an emptied token vault of `escrow`, returning its rent to `rent_receiver`.
+pub fn escrow_close_vault<'info>(
+    escrow: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+    tokens: &EscrowTokenAccounts<'info>,
+    rent_receiver: &AccountInfo<'info>,
+) -> Result<()> {
+    let (Some(vault), Some(token_program)) = (tokens.vault.as_ref(), tokens.token_program.as_ref()) else {
+        return err!(NuttooError::InvalidFeeAccounts);
+    };
+    token_interface::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: vault.to_account_info(),
+            destination: rent_receiver.clone(),
+            authority: escrow.clone(),
+        },
+        &[signer_seeds],
+    ))
+}
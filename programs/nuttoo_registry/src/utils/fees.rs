@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::constants::*;
+use crate::errors::NuttooError;
+use crate::events::RoyaltyAccrued;
+use crate::state::{royalty_share, Module, Policy, Registry, RoyaltyBalance, Treasury};
+
+/// Token accounts required when `Policy::fee_mint` is set; omitted for lamport fees.
+#[derive(Accounts)]
+pub struct FeeTokenAccounts<'info> {
+    pub fee_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Payer's token account for `fee_mint`.
+    #[account(mut)]
+    pub payer_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury's associated token account for `fee_mint`.
+    #[account(mut)]
+    pub fee_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+/// Charges `amount` into the treasury, in lamports or in the policy's fee mint.
+pub fn collect_fee<'info>(
+    policy: &Policy,
+    treasury: &mut Account<'info, Treasury>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    tokens: &FeeTokenAccounts<'info>,
+    amount: u64,
+) -> Result<()> {
+    let Some(fee_mint) = policy.fee_mint else {
+        return Treasury::collect(treasury, payer, system_program, amount);
+    };
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let (Some(mint), Some(from), Some(vault), Some(token_program)) = (
+        tokens.fee_mint.as_ref(),
+        tokens.payer_fee_account.as_ref(),
+        tokens.fee_vault.as_ref(),
+        tokens.token_program.as_ref(),
+    ) else {
+        return err!(NuttooError::InvalidFeeAccounts);
+    };
+
+    require_keys_eq!(mint.key(), fee_mint, NuttooError::InvalidFeeAccounts);
+    require_keys_eq!(*mint.to_account_info().owner, token_program.key(), NuttooError::InvalidFeeAccounts);
+    require_keys_eq!(
+        vault.key(),
+        get_associated_token_address_with_program_id(&treasury.key(), &fee_mint, &token_program.key()),
+        NuttooError::InvalidFeeAccounts
+    );
+    require_keys_eq!(from.mint, fee_mint, NuttooError::InvalidFeeAccounts);
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: vault.to_account_info(),
+                authority: payer.clone(),
+            },
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+/// Charges a run `fee` for `module`: royalties are routed up its lineage
+/// through `route_royalties` and the rest is collected into the treasury.
+/// Every instruction that records runs charges through here.
+#[allow(clippy::too_many_arguments)]
+pub fn collect_run_fee<'info>(
+    reg: &mut Account<'info, Registry>,
+    module: &Account<'info, Module>,
+    lineage: &'info [AccountInfo<'info>],
+    treasury: &mut Account<'info, Treasury>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    tokens: &FeeTokenAccounts<'info>,
+    fee: u64,
+) -> Result<()> {
+    let royalties = route_royalties(reg, module, lineage, payer, system_program, fee)?;
+    collect_fee(&reg.policy, treasury, payer, system_program, tokens, fee - royalties)
+}
+
+/// Pays royalties on a lamport `fee` charged for `module` to its upstream
+/// lineage, returning the amount routed; the rest is left for the treasury.
+/// `lineage` holds `(Module, RoyaltyBalance)` pairs, parent first, for up to
+/// `Policy::royalty_depth` levels. `Policy::validate` rejects royalties
+/// together with a `fee_mint`, so token fees never reach this split.
+pub fn route_royalties<'info>(
+    reg: &mut Account<'info, Registry>,
+    module: &Account<'info, Module>,
+    lineage: &'info [AccountInfo<'info>],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    fee: u64,
+) -> Result<u64> {
+    let policy = reg.policy;
+    if policy.fee_mint.is_some() || policy.royalty_depth == 0 || fee == 0 {
+        return Ok(0);
+    }
+
+    let max_depth = policy.royalty_depth.min(MAX_ROYALTY_DEPTH);
+    let mut pairs = lineage.chunks(2);
+    let mut parent = module.parent;
+    let mut level = 0u8;
+    let mut routed = 0u64;
+
+    while parent != Pubkey::default() && level < max_depth {
+        level += 1;
+        let Some([ancestor_info, balance_info]) = pairs.next() else {
+            return err!(NuttooError::InvalidLineage);
+        };
+        require_keys_eq!(ancestor_info.key(), parent, NuttooError::InvalidLineage);
+
+        let ancestor: Account<Module> = Account::try_from(ancestor_info)?;
+        let mut balance: Account<RoyaltyBalance> = Account::try_from(balance_info)?;
+        require_keys_eq!(ancestor.registry, reg.key(), NuttooError::InvalidLineage);
+        require_keys_eq!(balance.module, parent, NuttooError::InvalidLineage);
+
+        let share = royalty_share(fee, ancestor.royalty_bps, policy.royalty_decay_bps, level).min(fee - routed);
+        if share > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: balance_info.clone(),
+                    },
+                ),
+                share,
+            )?;
+            balance.accrued = balance.accrued.saturating_add(share);
+            balance.exit(&crate::ID)?;
+            routed += share;
+
+            emit!(RoyaltyAccrued {
+                event_seq: reg.next_event_seq(),
+                registry: reg.key(),
+                module: parent,
+                source_module: module.key(),
+                royalty_balance: balance.key(),
+                level,
+                amount: share,
+                accrued: balance.accrued,
+            });
+        }
+
+        parent = ancestor.parent;
+    }
+
+    Ok(routed)
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 use solana_program::hash::hashv;
+use crate::constants::*;
+use crate::utils::validate::normalize_module_name;
 
 pub fn sha256_32(data: &[u8]) -> [u8; 32] {
     // Solana hashv returns a 32-byte SHA256-based hash.
@@ -15,3 +17,168 @@ pub fn sha256_32_many(parts: &[&[u8]]) -> [u8; 32] {
 pub fn id_from_text(s: &str) -> [u8; 32] {
     sha256_32(s.as_bytes())
 }
+
+/// Canonical module id under `Policy.derived_ids`: owner plus normalized name.
+pub fn derive_module_id(owner: &Pubkey, name: &str) -> [u8; 32] {
+    sha256_32_many(&[MODULE_SEED, owner.as_ref(), normalize_module_name(name).as_bytes()])
+}
+
+/// Canonical fork id for `owner` forking `module`, where `nonce` is the
+/// module-wide `Module::fork_count` at creation time (not a per-owner count).
+pub fn derive_fork_id(module: &Pubkey, owner: &Pubkey, nonce: u64) -> [u8; 32] {
+    sha256_32_many(&[FORK_SEED, module.as_ref(), owner.as_ref(), &nonce.to_le_bytes()])
+}
+
+/// Canonical run id: the n-th run of `module`, bound to its inputs.
+pub fn derive_run_id(module: &Pubkey, inputs_sha256: &[u8; 32], nonce: u64) -> [u8; 32] {
+    sha256_32_many(&[RUN_SEED, module.as_ref(), inputs_sha256, &nonce.to_le_bytes()])
+}
+
+/// Seed for a module's `NameRecord`; names can exceed the 32-byte seed limit.
+pub fn name_hash(name: &str) -> [u8; 32] {
+    id_from_text(&normalize_module_name(name))
+}
+
+const MERKLE_LEAF_PREFIX: &[u8] = &[0x00];
+const MERKLE_NODE_PREFIX: &[u8] = &[0x01];
+
+pub fn merkle_leaf(data: &[u8]) -> [u8; 32] {
+    sha256_32_many(&[MERKLE_LEAF_PREFIX, data])
+}
+
+pub fn merkle_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    sha256_32_many(&[MERKLE_NODE_PREFIX, left, right])
+}
+
+fn merkle_parent_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [l, r] => merkle_node(l, r),
+            // An unpaired last node is hashed with itself.
+            [l] => merkle_node(l, l),
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Root over already-hashed leaves; the empty tree has an all-zero root.
+pub fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = merkle_parent_level(&level);
+    }
+    level[0]
+}
+
+/// Sibling path from leaf `index` up to the root.
+pub fn merkle_proof(leaves: &[[u8; 32]], mut index: usize) -> Vec<[u8; 32]> {
+    let mut proof = Vec::new();
+    if index >= leaves.len() {
+        return proof;
+    }
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        let sibling = index ^ 1;
+        proof.push(*level.get(sibling).unwrap_or(&level[index]));
+        level = merkle_parent_level(&level);
+        index /= 2;
+    }
+    proof
+}
+
+pub fn verify_merkle_proof(root: &[u8; 32], leaf: &[u8; 32], mut index: u64, proof: &[[u8; 32]]) -> bool {
+    let mut node = *leaf;
+    for sibling in proof {
+        node = if index & 1 == 0 {
+            merkle_node(&node, sibling)
+        } else {
+            merkle_node(sibling, &node)
+        };
+        index >>= 1;
+    }
+    index == 0 && node == *root
+}
+
+/// Leaf hash of a run receipt as committed by `commit_run_batch`.
+pub fn run_receipt_leaf(
+    run_id: &[u8; 32],
+    module: &Pubkey,
+    inputs_sha256: &[u8; 32],
+    outputs_sha256: &[u8; 32],
+    ok: bool,
+    exit_code: i32,
+    finished_at: i64,
+) -> [u8; 32] {
+    merkle_leaf(&[
+        run_id.as_ref(),
+        module.as_ref(),
+        inputs_sha256.as_ref(),
+        outputs_sha256.as_ref(),
+        &[ok as u8],
+        &exit_code.to_le_bytes(),
+        &finished_at.to_le_bytes(),
+    ]
+    .concat())
+}
+
+/// Leaf hash of one snapshot entry in a token mapping tree.
+pub fn mapping_leaf(index: u32, holder: &Pubkey, amount: u64) -> [u8; 32] {
+    merkle_leaf(&[MAPPING_SEED, &index.to_le_bytes(), holder.as_ref(), &amount.to_le_bytes()].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(n: u8) -> Vec<[u8; 32]> {
+        (0..n).map(|i| merkle_leaf(&[i])).collect()
+    }
+
+    #[test]
+    fn merkle_root_shapes() {
+        assert_eq!(merkle_root(&[]), [0u8; 32]);
+        let l = leaves(3);
+        assert_eq!(merkle_root(&l[..1]), l[0]);
+        assert_eq!(merkle_root(&l[..2]), merkle_node(&l[0], &l[1]));
+        // The unpaired third leaf is hashed with itself.
+        assert_eq!(
+            merkle_root(&l),
+            merkle_node(&merkle_node(&l[0], &l[1]), &merkle_node(&l[2], &l[2]))
+        );
+    }
+
+    #[test]
+    fn leaves_and_nodes_are_domain_separated() {
+        let (a, b) = (merkle_leaf(b"a"), merkle_leaf(b"b"));
+        assert_ne!(merkle_node(&a, &b), merkle_leaf(&[a, b].concat()));
+    }
+
+    #[test]
+    fn every_proof_verifies() {
+        for n in 1..=9 {
+            let l = leaves(n);
+            let root = merkle_root(&l);
+            for (i, leaf) in l.iter().enumerate() {
+                let proof = merkle_proof(&l, i);
+                assert!(verify_merkle_proof(&root, leaf, i as u64, &proof), "n={n} i={i}");
+            }
+        }
+    }
+
+    #[test]
+    fn bad_proofs_fail() {
+        let l = leaves(5);
+        let root = merkle_root(&l);
+        let proof = merkle_proof(&l, 2);
+        assert!(!verify_merkle_proof(&root, &l[3], 2, &proof));
+        assert!(!verify_merkle_proof(&root, &l[2], 3, &proof));
+        // An index beyond the proof's depth must not alias a shorter path.
+        assert!(!verify_merkle_proof(&root, &l[2], 2 + (1 << proof.len()), &proof));
+        assert!(!verify_merkle_proof(&root, &l[2], 2, &proof[1..]));
+        assert!(merkle_proof(&l, 5).is_empty());
+    }
+}
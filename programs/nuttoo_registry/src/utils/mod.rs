@@ -1,7 +1,11 @@
 pub mod hash;
 pub mod validate;
 pub mod pda;
+pub mod fees;
+pub mod escrow;
 
 pub use hash::*;
 pub use validate::*;
 pub use pda::*;
+pub use fees::*;
+pub use escrow::*;
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
+use crate::utils::hash::name_hash;
 
 pub fn registry_pda(program_id: &Pubkey, authority: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[REGISTRY_SEED, authority.as_ref()], program_id)
@@ -17,36 +18,10 @@ pub fn run_pda(program_id: &Pubkey, registry: &Pubkey, run_id: &[u8; 32]) -> (Pu
     Pubkey::find_program_address(&[RUN_SEED, registry.as_ref(), run_id], program_id)
 }
 
-use anchor_lang::prelude::*;
-use crate::constants::*;
-use crate::errors::NuttooError;
-
-pub fn require_nonempty(s: &str) -> Result<()> {
-    if s.trim().is_empty() {
-        return err!(NuttooError::InvalidInput);
-    }
-    Ok(())
-}
-
-pub fn require_max_len(s: &str, max: usize) -> Result<()> {
-    if s.len() > max {
-        return err!(NuttooError::InvalidInput);
-    }
-    Ok(())
-}
-
-pub fn validate_module_meta(name: &str, version: &str, description: &str, uri: &str) -> Result<()> {
-    require_nonempty(name)?;
-    require_nonempty(version)?;
-    require_max_len(name, MAX_NAME_LEN)?;
-    require_max_len(version, MAX_VERSION_LEN)?;
-    require_max_len(description, MAX_DESC_LEN)?;
-    require_max_len(uri, MAX_URI_LEN)?;
-    Ok(())
+pub fn name_record_pda(program_id: &Pubkey, registry: &Pubkey, name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[NAME_SEED, registry.as_ref(), &name_hash(name)], program_id)
 }
 
-pub fn validate_notes(notes: &str) -> Result<()> {
-    require_nonempty(notes)?;
-    require_max_len(notes, MAX_NOTES_LEN)?;
-    Ok(())
+pub fn treasury_pda(program_id: &Pubkey, registry: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TREASURY_SEED, registry.as_ref()], program_id)
 }
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::NuttooError;
+use crate::state::Policy;
 
 pub fn require_nonempty(s: &str) -> Result<()> {
     if s.trim().is_empty() {
@@ -31,3 +32,401 @@ pub fn validate_notes(notes: &str) -> Result<()> {
     require_max_len(notes, MAX_NOTES_LEN)?;
     Ok(())
 }
+
+pub fn validate_run_timing(
+    started_at: i64,
+    finished_at: i64,
+    wall_time_ms: u64,
+    peak_memory_bytes: u64,
+    now: i64,
+) -> Result<()> {
+    if started_at <= 0 || finished_at < started_at {
+        return err!(NuttooError::InvalidInput);
+    }
+    if finished_at > now.saturating_add(MAX_RUN_CLOCK_SKEW_SECS) {
+        return err!(NuttooError::InvalidInput);
+    }
+
+    // Timestamps have second resolution, so allow up to one extra second of wall time.
+    let span_ms = (finished_at - started_at).saturating_add(1).saturating_mul(1000) as u64;
+    if wall_time_ms > span_ms {
+        return err!(NuttooError::InvalidInput);
+    }
+
+    if peak_memory_bytes > MAX_RUN_PEAK_MEMORY_BYTES {
+        return err!(NuttooError::InvalidInput);
+    }
+    Ok(())
+}
+
+pub fn validate_run_metrics(
+    started_at: i64,
+    finished_at: i64,
+    wall_time_ms: u64,
+    peak_memory_bytes: u64,
+    runtime_version: &str,
+    now: i64,
+) -> Result<()> {
+    validate_run_timing(started_at, finished_at, wall_time_ms, peak_memory_bytes, now)?;
+    require_nonempty(runtime_version)?;
+    require_max_len(runtime_version, MAX_RUNTIME_VERSION_LEN)?;
+    Ok(())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum UriScheme {
+    Ipfs,
+    Arweave,
+    Https,
+    Sha256,
+}
+
+/// Splits a module URI into its scheme and the remainder after the scheme prefix.
+pub fn parse_uri(uri: &str) -> Result<(UriScheme, &str)> {
+    let (scheme, rest) = if let Some(rest) = uri.strip_prefix("ipfs://") {
+        (UriScheme::Ipfs, rest)
+    } else if let Some(rest) = uri.strip_prefix("ar://") {
+        (UriScheme::Arweave, rest)
+    } else if let Some(rest) = uri.strip_prefix("https://") {
+        (UriScheme::Https, rest)
+    } else if let Some(rest) = uri.strip_prefix("sha256:") {
+        (UriScheme::Sha256, rest)
+    } else {
+        return err!(NuttooError::InvalidInput);
+    };
+
+    if rest.is_empty() || !rest.bytes().all(|b| b.is_ascii_graphic()) {
+        return err!(NuttooError::InvalidInput);
+    }
+
+    match scheme {
+        UriScheme::Arweave => {
+            let ok = rest.len() == ARWEAVE_TX_ID_LEN
+                && rest.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+            if !ok {
+                return err!(NuttooError::InvalidInput);
+            }
+        }
+        UriScheme::Https => {
+            let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+            if host.is_empty() {
+                return err!(NuttooError::InvalidInput);
+            }
+        }
+        UriScheme::Sha256 => {
+            if decode_hex_32(rest).is_none() {
+                return err!(NuttooError::InvalidInput);
+            }
+        }
+        UriScheme::Ipfs => {}
+    }
+
+    Ok((scheme, rest))
+}
+
+/// Validates `meta.uri`; an empty URI means "no location".
+pub fn validate_uri(uri: &str) -> Result<()> {
+    require_max_len(uri, MAX_URI_LEN)?;
+    if uri.is_empty() {
+        return Ok(());
+    }
+    parse_uri(uri).map(|_| ())
+}
+
+/// Returns the SHA-256 digest of the content a URI commits to, if any:
+/// `sha256:<hex>` directly, or an `ipfs://` CIDv1 with the `raw` codec.
+/// Other CIDs hash a UnixFS node rather than the bytes and are rejected.
+pub fn uri_content_digest(uri: &str) -> Result<Option<[u8; 32]>> {
+    match parse_uri(uri)? {
+        (UriScheme::Sha256, hex) => Ok(decode_hex_32(hex)),
+        (UriScheme::Ipfs, rest) => {
+            let cid = rest.split('/').next().unwrap_or("");
+            cid_sha256_digest(cid).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Enforces `Policy.require_content_addressed_uri` for a module's URI and
+/// artifact, including the URI scheme checks of `validate_uri`.
+pub fn check_content_addressed_uri(policy: &Policy, uri: &str, artifact_sha256: &[u8; 32]) -> Result<()> {
+    if !policy.require_content_addressed_uri {
+        return Ok(());
+    }
+    validate_uri(uri)?;
+    match uri_content_digest(uri)? {
+        Some(digest) if digest == *artifact_sha256 => Ok(()),
+        Some(_) => err!(NuttooError::ArtifactHashMismatch),
+        None => err!(NuttooError::PolicyViolation),
+    }
+}
+
+fn decode_hex_32(s: &str) -> Option<[u8; 32]> {
+    if s.len() != 64 {
+        return None;
+    }
+    let mut out = [0u8; 32];
+    for (i, pair) in s.as_bytes().chunks(2).enumerate() {
+        let hi = (pair[0] as char).to_digit(16)?;
+        let lo = (pair[1] as char).to_digit(16)?;
+        out[i] = (hi * 16 + lo) as u8;
+    }
+    Some(out)
+}
+
+const MULTIHASH_SHA2_256: u8 = 0x12;
+const CID_CODEC_RAW: u8 = 0x55;
+
+/// Digest of a CIDv1 (multibase `b` base32 or `z` base58btc) with the `raw`
+/// codec and a sha2-256 multihash, which is sha256 of the content itself.
+/// CIDv0 (`Qm…`) and other codecs such as dag-pb hash the UnixFS node
+/// instead and fail with `UnsupportedCid`.
+fn cid_sha256_digest(cid: &str) -> Result<[u8; 32]> {
+    let raw = if let Some(b32) = cid.strip_prefix('b') {
+        decode_base32_lower(b32)
+    } else if let Some(b58) = cid.strip_prefix('z') {
+        decode_base58(b58)
+    } else {
+        return err!(NuttooError::UnsupportedCid);
+    };
+    let raw = raw.ok_or(NuttooError::InvalidInput)?;
+    match raw.as_slice() {
+        [0x01, CID_CODEC_RAW, MULTIHASH_SHA2_256, 0x20, digest @ ..] if digest.len() == 32 => {
+            Ok(digest.try_into().unwrap())
+        }
+        [0x01, ..] => err!(NuttooError::UnsupportedCid),
+        _ => err!(NuttooError::InvalidInput),
+    }
+}
+
+fn decode_base58(s: &str) -> Option<Vec<u8>> {
+    const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    let mut out: Vec<u8> = Vec::with_capacity(s.len());
+    for c in s.bytes() {
+        let mut carry = ALPHABET.iter().position(|&a| a == c)? as u32;
+        for b in out.iter_mut().rev() {
+            carry += (*b as u32) * 58;
+            *b = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            out.insert(0, (carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+    let zeros = s.bytes().take_while(|&c| c == b'1').count();
+    let mut res = vec![0u8; zeros];
+    res.extend(out);
+    Some(res)
+}
+
+fn decode_base32_lower(s: &str) -> Option<Vec<u8>> {
+    const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+    let mut out = Vec::with_capacity(s.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for c in s.bytes() {
+        let v = ALPHABET.iter().position(|&a| a == c)? as u32;
+        buffer = (buffer << 5) | v;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(out)
+}
+
+/// Target triples / runtime kinds: lowercase ASCII letters, digits, `.`, `_` and `-`.
+pub fn validate_target(target: &str) -> Result<()> {
+    require_nonempty(target)?;
+    require_max_len(target, MAX_TARGET_LEN)?;
+    let ok = target
+        .bytes()
+        .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'.' || b == b'_' || b == b'-');
+    if !ok {
+        return err!(NuttooError::InvalidInput);
+    }
+    Ok(())
+}
+
+/// Tags and category slugs: lowercase ASCII letters, digits and `-`.
+pub fn validate_slug(s: &str, max: usize) -> Result<()> {
+    require_nonempty(s)?;
+    require_max_len(s, max)?;
+    let ok = s.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-');
+    if !ok || s.starts_with('-') || s.ends_with('-') {
+        return err!(NuttooError::InvalidInput);
+    }
+    Ok(())
+}
+
+pub fn validate_tags(tags: &[String]) -> Result<()> {
+    if tags.len() > MAX_TAGS {
+        return err!(NuttooError::InvalidInput);
+    }
+    for (i, tag) in tags.iter().enumerate() {
+        validate_slug(tag, MAX_TAG_LEN)?;
+        if tags[..i].contains(tag) {
+            return err!(NuttooError::InvalidInput);
+        }
+    }
+    Ok(())
+}
+
+/// Canonical form used for name uniqueness: ASCII lowercase, with `_`, `.`
+/// and spaces folded to `-`, so "Demo_Module" and "demo-module" collide.
+pub fn normalize_module_name(name: &str) -> String {
+    name.trim()
+        .chars()
+        .map(|c| match c {
+            '_' | '.' | ' ' => '-',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect()
+}
+
+/// Module names are restricted to ASCII letters, digits, `-`, `_`, `.` and
+/// inner spaces. Non-ASCII is rejected outright rather than Unicode-normalized
+/// so that confusable characters cannot produce look-alike names.
+pub fn validate_module_name(name: &str) -> Result<()> {
+    require_nonempty(name)?;
+    require_max_len(name, MAX_NAME_LEN)?;
+    let ok = name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ' '));
+    if !ok || name.trim() != name {
+        return err!(NuttooError::InvalidInput);
+    }
+    let normalized = normalize_module_name(name);
+    if normalized.starts_with('-') || normalized.ends_with('-') || normalized.contains("--") {
+        return err!(NuttooError::InvalidInput);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::hash::{name_hash, sha256_32};
+
+    const EMPTY_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+    fn is_error(r: Result<[u8; 32]>, e: NuttooError) -> bool {
+        matches!(r, Err(Error::AnchorError(a)) if a.error_code_number == u32::from(e))
+    }
+
+    const NOW: i64 = 1_700_000_000;
+    const MIB: u64 = 1 << 20;
+
+    #[test]
+    fn run_timing_order_and_clock() {
+        assert!(validate_run_timing(NOW - 10, NOW - 5, 5_000, MIB, NOW).is_ok());
+        assert!(validate_run_timing(NOW - 5, NOW - 5, 0, MIB, NOW).is_ok());
+        assert!(validate_run_timing(NOW - 5, NOW - 6, 0, MIB, NOW).is_err());
+        assert!(validate_run_timing(0, NOW, 0, MIB, NOW).is_err());
+        assert!(validate_run_timing(NOW, NOW + MAX_RUN_CLOCK_SKEW_SECS, 0, MIB, NOW).is_ok());
+        assert!(validate_run_timing(NOW, NOW + MAX_RUN_CLOCK_SKEW_SECS + 1, 0, MIB, NOW).is_err());
+    }
+
+    #[test]
+    fn run_wall_time_fits_elapsed() {
+        // Second-resolution timestamps leave up to one extra second of wall time.
+        assert!(validate_run_timing(NOW - 2, NOW, 3_000, MIB, NOW).is_ok());
+        assert!(validate_run_timing(NOW - 2, NOW, 3_001, MIB, NOW).is_err());
+        assert!(validate_run_timing(NOW, NOW, 1_000, MIB, NOW).is_ok());
+        assert!(validate_run_timing(NOW, NOW, 1_001, MIB, NOW).is_err());
+    }
+
+    #[test]
+    fn run_memory_and_runtime_bounds() {
+        assert!(validate_run_timing(NOW - 1, NOW, 0, MAX_RUN_PEAK_MEMORY_BYTES, NOW).is_ok());
+        assert!(validate_run_timing(NOW - 1, NOW, 0, MAX_RUN_PEAK_MEMORY_BYTES + 1, NOW).is_err());
+        assert!(validate_run_metrics(NOW - 1, NOW, 500, MIB, "20.0.0", NOW).is_ok());
+        assert!(validate_run_metrics(NOW - 1, NOW, 500, MIB, "", NOW).is_err());
+        assert!(validate_run_metrics(NOW - 1, NOW, 500, MIB, &"9".repeat(MAX_RUNTIME_VERSION_LEN + 1), NOW).is_err());
+        assert!(validate_run_metrics(NOW, NOW - 1, 500, MIB, "20.0.0", NOW).is_err());
+    }
+
+    #[test]
+    fn base58_vectors() {
+        assert_eq!(decode_base58("StV1DL6CwTryKyV").unwrap(), b"hello world");
+        assert_eq!(decode_base58("11").unwrap(), vec![0, 0]);
+        assert_eq!(decode_base58("11111111111111111111111111111111").unwrap(), vec![0u8; 32]);
+        assert_eq!(decode_base58("").unwrap(), Vec::<u8>::new());
+        assert!(decode_base58("0OIl").is_none());
+    }
+
+    #[test]
+    fn base32_lower_vectors() {
+        // RFC 4648 vectors, lowercased and unpadded.
+        assert_eq!(decode_base32_lower("my").unwrap(), b"f");
+        assert_eq!(decode_base32_lower("mzxq").unwrap(), b"fo");
+        assert_eq!(decode_base32_lower("mzxw6").unwrap(), b"foo");
+        assert_eq!(decode_base32_lower("mzxw6yq").unwrap(), b"foob");
+        assert_eq!(decode_base32_lower("mzxw6ytboi").unwrap(), b"foobar");
+        assert!(decode_base32_lower("MZXW6").is_none());
+        assert!(decode_base32_lower("mzx1").is_none());
+    }
+
+    #[test]
+    fn raw_cidv1_digest() {
+        // `ipfs add --cid-version 1 --raw-leaves` of an empty file.
+        let digest = cid_sha256_digest("bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku").unwrap();
+        assert_eq!(digest, decode_hex_32(EMPTY_SHA256).unwrap());
+
+        let digest = cid_sha256_digest("bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e").unwrap();
+        assert_eq!(digest, sha256_32(b"hello world"));
+    }
+
+    #[test]
+    fn rejects_unixfs_cids() {
+        // CIDv0 and CIDv1 dag-pb hash the UnixFS node, not the file bytes.
+        assert!(is_error(
+            cid_sha256_digest("QmbFMke1KXqnYyBBWxB74N4c5SBnJMVAiMNRcGu6x1AwQH"),
+            NuttooError::UnsupportedCid
+        ));
+        assert!(is_error(
+            cid_sha256_digest("bafybeihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"),
+            NuttooError::UnsupportedCid
+        ));
+        assert!(is_error(cid_sha256_digest("bnot-base32"), NuttooError::InvalidInput));
+    }
+
+    #[test]
+    fn content_addressed_policy() {
+        let policy = Policy { require_content_addressed_uri: true, ..Policy::default() };
+        let empty = decode_hex_32(EMPTY_SHA256).unwrap();
+        let raw = "ipfs://bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku";
+        assert!(check_content_addressed_uri(&policy, raw, &empty).is_ok());
+        assert!(check_content_addressed_uri(&policy, &format!("sha256:{EMPTY_SHA256}"), &empty).is_ok());
+        assert!(check_content_addressed_uri(&policy, raw, &[1u8; 32]).is_err());
+        assert!(check_content_addressed_uri(&policy, "https://example.com/a.tgz", &empty).is_err());
+        assert!(check_content_addressed_uri(&Policy::default(), "ftp://anything", &empty).is_ok());
+    }
+
+    #[test]
+    fn meta_uri_is_length_checked_only() {
+        assert!(validate_module_meta("m", "0.1.0", "", "ftp://legacy").is_ok());
+        assert!(validate_module_meta("m", "0.1.0", "", &"x".repeat(MAX_URI_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn normalized_names_collide() {
+        assert_eq!(normalize_module_name("Demo_Module"), "demo-module");
+        assert_eq!(normalize_module_name("demo.module"), "demo-module");
+        assert_eq!(normalize_module_name("Demo Module"), "demo-module");
+        assert_eq!(normalize_module_name("  demo-module "), "demo-module");
+        assert_ne!(normalize_module_name("demo-module2"), normalize_module_name("demo-module"));
+        assert_eq!(name_hash("Demo_Module"), name_hash("demo-module"));
+    }
+
+    #[test]
+    fn module_names_are_ascii() {
+        assert!(validate_module_name("Demo_Module.v2 beta").is_ok());
+        assert!(validate_module_name("dеmo").is_err()); // Cyrillic "е"
+        assert!(validate_module_name(" demo").is_err());
+        assert!(validate_module_name("demo/x").is_err());
+    }
+}
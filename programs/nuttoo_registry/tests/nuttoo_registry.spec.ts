@@ -1,5 +1,15 @@
 import * as anchor from "@coral-xyz/anchor";
 import { expect } from "chai";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
+
+import { encodeCanonicalManifest } from "../../../packages/module-kit/src/pack/canonical-manifest";
 
 import fs from "node:fs";
 import path from "node:path";
@@ -10,6 +20,24 @@ function sha25632(buf: Buffer): number[] {
   return Array.from(h.slice(0, 32));
 }
 
+// Mirrors utils::hash: domain-separated sha256 leaves and nodes, odd nodes paired with themselves.
+const sha256 = (...parts: Buffer[]) => crypto.createHash("sha256").update(Buffer.concat(parts)).digest();
+const merkleLeaf = (data: Buffer) => sha256(Buffer.from([0]), data);
+const merkleNode = (l: Buffer, r: Buffer) => sha256(Buffer.from([1]), l, r);
+
+function merkleRootAndProof(leaves: Buffer[], index: number): { root: Buffer; proof: Buffer[] } {
+  const proof: Buffer[] = [];
+  let level = leaves;
+  while (level.length > 1) {
+    proof.push(level[index ^ 1] ?? level[index]);
+    const next: Buffer[] = [];
+    for (let i = 0; i < level.length; i += 2) next.push(merkleNode(level[i], level[i + 1] ?? level[i]));
+    level = next;
+    index >>= 1;
+  }
+  return { root: level[0], proof };
+}
+
 describe("nuttoo_registry", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
@@ -17,12 +45,244 @@ describe("nuttoo_registry", () => {
   const program = anchor.workspace.NuttooRegistry as anchor.Program;
   const authority = provider.wallet.publicKey;
 
-  it("initializes registry and publishes module", async () => {
-    const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("registry"), authority.toBuffer()],
-      program.programId
+  const [registryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("registry"), authority.toBuffer()],
+    program.programId
+  );
+  const [treasuryPda] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury"), registryPda.toBuffer()],
+    program.programId
+  );
+  const royaltyPda = (module: anchor.web3.PublicKey) =>
+    anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("royalty"), module.toBuffer()], program.programId)[0];
+  const noFeeTokens = { feeMint: null, payerFeeAccount: null, feeVault: null, tokenProgram: null };
+  const noEscrowTokens = { mint: null, counterparty: null, vault: null, tokenProgram: null };
+  const systemProgram = anchor.web3.SystemProgram.programId;
+  const zero32 = Array(32).fill(0);
+
+  const pda = (...seeds: Buffer[]) => anchor.web3.PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+  const stakeOf = (publisher: anchor.web3.PublicKey) =>
+    pda(Buffer.from("stake"), registryPda.toBuffer(), publisher.toBuffer());
+  const chainNow = async () => (await provider.connection.getBlockTime(await provider.connection.getSlot()))!;
+  const sleep = (ms: number) => new Promise((r) => setTimeout(r, ms));
+
+  async function fundedKeypair(lamports = anchor.web3.LAMPORTS_PER_SOL): Promise<anchor.web3.Keypair> {
+    const kp = anchor.web3.Keypair.generate();
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({ fromPubkey: authority, toPubkey: kp.publicKey, lamports })
+      )
     );
+    return kp;
+  }
+
+  type PublishOptions = {
+    artifact?: Buffer;
+    publisher?: anchor.web3.Keypair;
+    promote?: { fork: anchor.web3.PublicKey; parent: anchor.web3.PublicKey };
+    royaltyBps?: number;
+  };
+
+  async function publish(name: string, opts: PublishOptions = {}): Promise<anchor.web3.PublicKey> {
+    const { artifact = Buffer.from(`${name}-artifact`), publisher, promote, royaltyBps = 0 } = opts;
+    const owner = publisher ? publisher.publicKey : authority;
+    const moduleIdBytes = sha25632(Buffer.from(name));
+    const modulePda = pda(Buffer.from("module"), registryPda.toBuffer(), Buffer.from(moduleIdBytes));
+    await program.methods
+      .publishModule({
+        moduleId: Buffer.from(moduleIdBytes) as any,
+        name,
+        version: "0.1.0",
+        description: "spec module",
+        uri: "ipfs://example",
+        manifestSha256: Buffer.from(sha25632(Buffer.from(name))) as any,
+        manifest: null,
+        artifactSha256: Buffer.from(sha25632(artifact)) as any,
+        artifactSize: new anchor.BN(artifact.length),
+        verified: true,
+        tags: [],
+        royaltyBps,
+      })
+      .accounts({
+        registry: registryPda,
+        module: modulePda,
+        nameRecord: pda(Buffer.from("name"), registryPda.toBuffer(), Buffer.from(sha25632(Buffer.from(name)))),
+        royaltyBalance: royaltyPda(modulePda),
+        sourceFork: promote ? promote.fork : null,
+        parentModule: promote ? promote.parent : null,
+        stake: stakeOf(owner),
+        treasury: treasuryPda,
+        feeTokens: noFeeTokens,
+        authority,
+        publisher: owner,
+        payer: authority,
+        systemProgram,
+      })
+      .signers(publisher ? [publisher] : [])
+      .rpc();
+    return modulePda;
+  }
 
+  async function eventsOf(signature: string): Promise<anchor.Event[]> {
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    return [...new anchor.EventParser(program.programId, program.coder).parseLogs(tx!.meta!.logMessages!)];
+  }
+
+  async function registerWorker(worker: anchor.web3.PublicKey): Promise<anchor.web3.PublicKey> {
+    const workerPda = pda(Buffer.from("worker"), registryPda.toBuffer(), worker.toBuffer());
+    await program.methods
+      .registerWorker({ worker })
+      .accounts({ registry: registryPda, worker: workerPda, authority, payer: authority, systemProgram })
+      .rpc();
+    return workerPda;
+  }
+
+  type EpochAccounts = { rewardEpoch: anchor.web3.PublicKey | null; moduleUsage: anchor.web3.PublicKey | null };
+  const noEpoch: EpochAccounts = { rewardEpoch: null, moduleUsage: null };
+
+  async function createFork(
+    module: anchor.web3.PublicKey,
+    forker: anchor.web3.Keypair | null = null,
+    epoch: EpochAccounts = noEpoch
+  ): Promise<anchor.web3.PublicKey> {
+    const forkId = sha25632(crypto.randomBytes(32));
+    const forkPda = pda(Buffer.from("fork"), registryPda.toBuffer(), Buffer.from(forkId));
+    const version = ((await program.account.module.fetch(module)) as any).meta.version;
+    await program.methods
+      .createFork({ forkId, notes: "", pinnedVersion: null })
+      .accounts({
+        registry: registryPda,
+        module,
+        fork: forkPda,
+        moduleVersion: pda(Buffer.from("version"), module.toBuffer(), Buffer.from(version)),
+        ...epoch,
+        treasury: treasuryPda,
+        feeTokens: noFeeTokens,
+        authority: forker ? forker.publicKey : authority,
+        payer: authority,
+        systemProgram,
+      })
+      .signers(forker ? [forker] : [])
+      .rpc();
+    return forkPda;
+  }
+
+  async function runResult(inputsSha256: number[], ok = true) {
+    const now = await chainNow();
+    return {
+      runId: sha25632(crypto.randomBytes(32)),
+      fork: anchor.web3.PublicKey.default,
+      ok,
+      exitCode: ok ? 0 : 1,
+      stdoutSha256: zero32,
+      stderrSha256: zero32,
+      inputsSha256,
+      outputsSha256: sha25632(Buffer.from(ok ? "outputs" : "no-outputs")),
+      startedAt: new anchor.BN(now - 2),
+      finishedAt: new anchor.BN(now - 1),
+      wallTimeMs: new anchor.BN(500),
+      peakMemoryBytes: new anchor.BN(1 << 20),
+      runtimeKind: { node: {} },
+      runtimeVersion: "20.0.0",
+    };
+  }
+
+  const runPdaOf = (runId: number[]) => pda(Buffer.from("run"), registryPda.toBuffer(), Buffer.from(runId));
+
+  async function recordRun(
+    module: anchor.web3.PublicKey,
+    workerPda: anchor.web3.PublicKey,
+    inputsSha256: number[],
+    ok = true,
+    { epoch = noEpoch, lineage = [] as anchor.web3.PublicKey[] } = {}
+  ): Promise<anchor.web3.PublicKey> {
+    const result = await runResult(inputsSha256, ok);
+    const runPda = runPdaOf(result.runId);
+    await program.methods
+      .recordRun(result)
+      .accounts({
+        registry: registryPda,
+        module,
+        run: runPda,
+        worker: workerPda,
+        ...epoch,
+        treasury: treasuryPda,
+        feeTokens: noFeeTokens,
+        authority,
+        payer: authority,
+        systemProgram,
+      })
+      .remainingAccounts(lineage.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+      .rpc();
+    return runPda;
+  }
+
+  async function logRuns(
+    module: anchor.web3.PublicKey,
+    workerPda: anchor.web3.PublicKey | null,
+    count: number
+  ): Promise<string> {
+    const now = await chainNow();
+    const entries = Array.from({ length: count }, (_, n) => ({
+      ok: true,
+      exitCode: 0,
+      inputsSha256: sha25632(Buffer.from(`logged-inputs-${n}`)),
+      outputsSha256: sha25632(Buffer.from(`logged-outputs-${n}`)),
+      startedAt: new anchor.BN(now - 2),
+      finishedAt: new anchor.BN(now - 1),
+      wallTimeMs: new anchor.BN(100),
+      peakMemoryBytes: new anchor.BN(1 << 20),
+      runtimeKind: { node: {} },
+    }));
+    return program.methods
+      .recordRunsBatch({ entries })
+      .accounts({
+        registry: registryPda,
+        module,
+        runLog: pda(Buffer.from("run_log"), registryPda.toBuffer(), module.toBuffer()),
+        worker: workerPda,
+        treasury: treasuryPda,
+        feeTokens: noFeeTokens,
+        authority,
+        payer: authority,
+        systemProgram,
+      })
+      .rpc();
+  }
+
+  async function commitRuns(
+    module: anchor.web3.PublicKey,
+    workerPda: anchor.web3.PublicKey | null,
+    count: number
+  ): Promise<string> {
+    const now = await chainNow();
+    const batchId = sha25632(crypto.randomBytes(32));
+    return program.methods
+      .commitRunBatch({
+        batchId,
+        merkleRoot: sha25632(Buffer.from(`committed-root-${count}`)),
+        count,
+        firstRunAt: new anchor.BN(now - 1),
+        lastRunAt: new anchor.BN(now - 1),
+      })
+      .accounts({
+        registry: registryPda,
+        module,
+        runBatch: pda(Buffer.from("run_batch"), registryPda.toBuffer(), Buffer.from(batchId)),
+        worker: workerPda,
+        treasury: treasuryPda,
+        feeTokens: noFeeTokens,
+        authority,
+        payer: authority,
+        systemProgram,
+      })
+      .rpc();
+  }
+
+  it("initializes registry and publishes module", async () => {
     // Init registry (idempotent test: ignore if already exists).
     try {
       await program.methods
@@ -33,10 +293,30 @@ describe("nuttoo_registry", () => {
             allowUnverified: true,
             maxArtifactBytes: new anchor.BN(10_000_000),
             maxRunsPerModule: new anchor.BN(10_000),
+            disputeQuorum: 2,
+            quarantineAfterFailures: 0,
+            runWindowSecs: 3600,
+            maxModuleRunsPerWindow: 600,
+            maxWorkerRunsPerWindow: 600,
+            requireContentAddressedUri: false,
+            maxVersionArtifactBytes: new anchor.BN(0),
+            derivedIds: false,
+            publishFeeLamports: new anchor.BN(0),
+            forkFeeLamports: new anchor.BN(0),
+            runFeeLamports: new anchor.BN(0),
+            feeMint: null,
+            maxRoyaltyBps: 0,
+            royaltyDepth: 0,
+            royaltyDecayBps: 0,
+            minPublishStake: new anchor.BN(0),
+            unstakeCooldownSecs: 0,
+            reputation: { okRun: 0, failedRun: 0, fork: 0, attestation: 0, ageDay: 0, maxAgeDays: 0 },
+            reviewRequiresRun: false,
           },
         })
         .accounts({
           registry: registryPda,
+          treasury: treasuryPda,
           payer: authority,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -49,8 +329,13 @@ describe("nuttoo_registry", () => {
       program.programId
     );
 
+    const [nameRecordPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("name"), registryPda.toBuffer(), Buffer.from(sha25632(Buffer.from("demo-module")))],
+      program.programId
+    );
+
     const manifestPath = path.join("tests", "fixtures", "module-manifest.json");
-    const manifestBuf = Buffer.from(fs.readFileSync(manifestPath, "utf-8"), "utf-8");
+    const manifestBuf = encodeCanonicalManifest(JSON.parse(fs.readFileSync(manifestPath, "utf-8")));
 
     const artifactPath = path.join("tests", "fixtures", "sample-artifact.tgz");
     const artifactBuf = fs.readFileSync(artifactPath);
@@ -63,14 +348,25 @@ describe("nuttoo_registry", () => {
         description: "fixture publish",
         uri: "ipfs://example",
         manifestSha256: Buffer.from(sha25632(manifestBuf)) as any,
+        manifest: manifestBuf,
         artifactSha256: Buffer.from(sha25632(artifactBuf)) as any,
         artifactSize: new anchor.BN(artifactBuf.length),
         verified: true,
+        tags: ["demo"],
+        royaltyBps: 0,
       })
       .accounts({
         registry: registryPda,
         module: modulePda,
+        nameRecord: nameRecordPda,
+        royaltyBalance: royaltyPda(modulePda),
+        sourceFork: null,
+        parentModule: null,
+        stake: stakeOf(authority),
+        treasury: treasuryPda,
+        feeTokens: noFeeTokens,
         authority,
+        publisher: authority,
         payer: authority,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -80,5 +376,1159 @@ describe("nuttoo_registry", () => {
     expect(moduleAccount.owner.toBase58()).to.eq(authority.toBase58());
     expect(moduleAccount.meta.name).to.eq("demo-module");
     expect(moduleAccount.verified).to.eq(true);
+    expect(moduleAccount.tags).to.deep.eq(["demo"]);
+  });
+
+  it("charges publish fees in a Token-2022 mint", async () => {
+    const payer = (provider.wallet as anchor.Wallet).payer;
+
+    // Mock fee mint and a funded payer account on the local validator.
+    const feeMint = await createMint(
+      provider.connection,
+      payer,
+      authority,
+      null,
+      0,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const payerFeeAccount = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      payer,
+      feeMint,
+      authority,
+      false,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await mintTo(
+      provider.connection,
+      payer,
+      feeMint,
+      payerFeeAccount.address,
+      authority,
+      100,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const feeVault = getAssociatedTokenAddressSync(feeMint, treasuryPda, true, TOKEN_2022_PROGRAM_ID);
+
+    const registryAccount: any = await program.account.registry.fetch(registryPda);
+    const basePolicy = registryAccount.policy;
+
+    await program.methods
+      .setPolicy({ policy: { ...basePolicy, feeMint, publishFeeLamports: new anchor.BN(5) } })
+      .accounts({ registry: registryPda, authority })
+      .rpc();
+
+    await program.methods
+      .initFeeVault()
+      .accounts({
+        registry: registryPda,
+        treasury: treasuryPda,
+        feeMint,
+        feeVault,
+        payer: authority,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const moduleIdBytes = sha25632(Buffer.from("fee-module"));
+    const [modulePda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("module"), registryPda.toBuffer(), Buffer.from(moduleIdBytes)],
+      program.programId
+    );
+    const [nameRecordPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("name"), registryPda.toBuffer(), Buffer.from(sha25632(Buffer.from("fee-module")))],
+      program.programId
+    );
+
+    try {
+      await program.methods
+        .publishModule({
+          moduleId: Buffer.from(moduleIdBytes) as any,
+          name: "fee-module",
+          version: "0.1.0",
+          description: "token fee publish",
+          uri: "ipfs://example",
+          manifestSha256: Buffer.from(sha25632(Buffer.from("fee-module"))) as any,
+          manifest: null,
+          artifactSha256: Buffer.from(sha25632(Buffer.from("fee-artifact"))) as any,
+          artifactSize: new anchor.BN(12),
+          verified: true,
+          tags: [],
+          royaltyBps: 0,
+        })
+        .accounts({
+          registry: registryPda,
+          module: modulePda,
+          nameRecord: nameRecordPda,
+          royaltyBalance: royaltyPda(modulePda),
+          sourceFork: null,
+          parentModule: null,
+          stake: stakeOf(authority),
+          treasury: treasuryPda,
+          feeTokens: {
+            feeMint,
+            payerFeeAccount: payerFeeAccount.address,
+            feeVault,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          },
+          authority,
+          publisher: authority,
+          payer: authority,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      const vault = await getAccount(provider.connection, feeVault, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(Number(vault.amount)).to.eq(5);
+
+      await program.methods
+        .withdrawTreasuryTokens({ amount: new anchor.BN(5) })
+        .accounts({
+          registry: registryPda,
+          treasury: treasuryPda,
+          feeMint,
+          feeVault,
+          recipient: payerFeeAccount.address,
+          authority,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      const payerAccount = await getAccount(provider.connection, payerFeeAccount.address, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(Number(payerAccount.amount)).to.eq(100);
+    } finally {
+      await program.methods
+        .setPolicy({ policy: { ...basePolicy, feeMint: null } })
+        .accounts({ registry: registryPda, authority })
+        .rpc();
+    }
+  });
+
+  async function addAttester(): Promise<anchor.web3.Keypair> {
+    const kp = anchor.web3.Keypair.generate();
+    await program.methods
+      .addAttester({ attester: kp.publicKey })
+      .accounts({
+        registry: registryPda,
+        attester: pda(Buffer.from("attester"), registryPda.toBuffer(), kp.publicKey.toBuffer()),
+        authority,
+        payer: authority,
+        systemProgram,
+      })
+      .rpc();
+    return kp;
+  }
+
+  async function rejects(p: Promise<unknown>, code: string) {
+    try {
+      await p;
+    } catch (e: any) {
+      expect(String(e)).to.contain(code);
+      return;
+    }
+    expect.fail(`expected ${code}`);
+  }
+
+  it("settles a challenged run by attester quorum", async () => {
+    const modulePda = await publish("dispute-module");
+    const worker = anchor.web3.Keypair.generate().publicKey;
+    const workerPda = await registerWorker(worker);
+    const inputs = sha25632(Buffer.from("dispute-inputs"));
+    const originalRun = await recordRun(modulePda, workerPda, inputs);
+
+    const challenge = await runResult(inputs, false);
+    const challengeRun = runPdaOf(challenge.runId);
+    const dispute = pda(Buffer.from("dispute"), registryPda.toBuffer(), originalRun.toBuffer());
+    const challengeWith = (workerAccount: anchor.web3.PublicKey | null) =>
+      program.methods
+        .challengeRun({ result: challenge })
+        .accounts({
+          registry: registryPda,
+          module: modulePda,
+          originalRun,
+          challengeRun,
+          dispute,
+          worker: workerAccount,
+          rewardEpoch: null,
+          moduleUsage: null,
+          attester: null,
+          treasury: treasuryPda,
+          feeTokens: noFeeTokens,
+          challenger: authority,
+          payer: authority,
+          systemProgram,
+        })
+        .rpc();
+
+    // Re-runs go through the same worker window as recorded runs.
+    await rejects(challengeWith(null), "WorkerRequired");
+    await challengeWith(workerPda);
+    expect(((await program.account.worker.fetch(workerPda)) as any).runCount.toNumber()).to.eq(2);
+    expect(((await program.account.run.fetch(challengeRun)) as any).worker.toBase58()).to.eq(worker.toBase58());
+    expect(((await program.account.run.fetch(originalRun)) as any).disputed).to.eq(true);
+
+    const [first, second] = [await addAttester(), await addAttester()];
+    const vote = (kp: anchor.web3.Keypair) =>
+      program.methods
+        .resolveDispute({ verdict: { challengeCorrect: {} } })
+        .accounts({
+          registry: registryPda,
+          dispute,
+          originalRun,
+          challengeRun,
+          attester: pda(Buffer.from("attester"), registryPda.toBuffer(), kp.publicKey.toBuffer()),
+          resolver: kp.publicKey,
+        })
+        .signers([kp])
+        .rpc();
+
+    // The spec registry's quorum is two votes.
+    await vote(first);
+    expect(((await program.account.runDispute.fetch(dispute)) as any).verdict).to.eq(null);
+    await rejects(vote(first), "AlreadyVoted");
+    await vote(second);
+
+    const resolved: any = await program.account.runDispute.fetch(dispute);
+    expect(resolved.verdict).to.deep.eq({ challengeCorrect: {} });
+    expect(resolved.votesChallenge).to.eq(2);
+    expect(((await program.account.run.fetch(originalRun)) as any).disputed).to.eq(true);
+    expect(((await program.account.run.fetch(challengeRun)) as any).disputed).to.eq(false);
+    await rejects(vote(second), "DisputeAlreadyResolved");
+  });
+
+  it("keeps the latest batched runs in a ring-buffer run log", async () => {
+    const modulePda = await publish("run-log-module");
+    const workerPda = await registerWorker(anchor.web3.Keypair.generate().publicKey);
+    const runLog = pda(Buffer.from("run_log"), registryPda.toBuffer(), modulePda.toBuffer());
+    await program.methods
+      .initRunLog({ capacity: 2 })
+      .accounts({ registry: registryPda, module: modulePda, runLog, authority, payer: authority, systemProgram })
+      .rpc();
+
+    const now = await chainNow();
+    const entry = (n: number, ok: boolean) => ({
+      ok,
+      exitCode: ok ? 0 : 1,
+      inputsSha256: sha25632(Buffer.from(`batch-inputs-${n}`)),
+      outputsSha256: sha25632(Buffer.from(`batch-outputs-${n}`)),
+      startedAt: new anchor.BN(now - 2),
+      finishedAt: new anchor.BN(now - 1),
+      wallTimeMs: new anchor.BN(100),
+      peakMemoryBytes: new anchor.BN(1 << 20),
+      runtimeKind: { node: {} },
+    });
+    await program.methods
+      .recordRunsBatch({ entries: [entry(0, true), entry(1, false), entry(2, true)] })
+      .accounts({
+        registry: registryPda,
+        module: modulePda,
+        runLog,
+        worker: workerPda,
+        treasury: treasuryPda,
+        feeTokens: noFeeTokens,
+        authority,
+        payer: authority,
+        systemProgram,
+      })
+      .rpc();
+
+    // Three runs into two slots: the oldest was overwritten.
+    const log: any = await program.account.runLog.fetch(runLog);
+    expect(log.capacity).to.eq(2);
+    expect(log.len).to.eq(2);
+    expect(log.head).to.eq(1);
+    expect(log.total.toNumber()).to.eq(3);
+
+    const m: any = await program.account.module.fetch(modulePda);
+    expect(m.runCount.toNumber()).to.eq(3);
+    expect(m.runStats.okCount.toNumber()).to.eq(2);
+    expect(m.runStats.failCount.toNumber()).to.eq(1);
+  });
+
+  it("commits run batches as Merkle roots and verifies inclusion proofs", async () => {
+    const modulePda = await publish("batch-module");
+    const workerPda = await registerWorker(anchor.web3.Keypair.generate().publicKey);
+    const now = await chainNow();
+    const receipts = [0, 1, 2].map((n) => ({
+      runId: sha25632(crypto.randomBytes(32)),
+      inputsSha256: sha25632(Buffer.from(`receipt-inputs-${n}`)),
+      outputsSha256: sha25632(Buffer.from(`receipt-outputs-${n}`)),
+      ok: n !== 1,
+      exitCode: n === 1 ? 2 : 0,
+      finishedAt: now - 10 + n,
+    }));
+    const leaves = receipts.map((r) => {
+      const exitCode = Buffer.alloc(4);
+      exitCode.writeInt32LE(r.exitCode);
+      const finishedAt = Buffer.alloc(8);
+      finishedAt.writeBigInt64LE(BigInt(r.finishedAt));
+      return merkleLeaf(
+        Buffer.concat([
+          Buffer.from(r.runId),
+          modulePda.toBuffer(),
+          Buffer.from(r.inputsSha256),
+          Buffer.from(r.outputsSha256),
+          Buffer.from([r.ok ? 1 : 0]),
+          exitCode,
+          finishedAt,
+        ])
+      );
+    });
+    const { root } = merkleRootAndProof(leaves, 0);
+
+    const batchId = sha25632(crypto.randomBytes(32));
+    const runBatch = pda(Buffer.from("run_batch"), registryPda.toBuffer(), Buffer.from(batchId));
+    await program.methods
+      .commitRunBatch({
+        batchId,
+        merkleRoot: Array.from(root),
+        count: receipts.length,
+        firstRunAt: new anchor.BN(receipts[0].finishedAt),
+        lastRunAt: new anchor.BN(receipts[2].finishedAt),
+      })
+      .accounts({
+        registry: registryPda,
+        module: modulePda,
+        runBatch,
+        worker: workerPda,
+        treasury: treasuryPda,
+        feeTokens: noFeeTokens,
+        authority,
+        payer: authority,
+        systemProgram,
+      })
+      .rpc();
+    expect(((await program.account.module.fetch(modulePda)) as any).runCount.toNumber()).to.eq(3);
+
+    const verify = (index: number, exitCode: number) => {
+      const r = receipts[index];
+      return program.methods
+        .verifyRunInclusion({
+          runId: r.runId,
+          inputsSha256: r.inputsSha256,
+          outputsSha256: r.outputsSha256,
+          ok: r.ok,
+          exitCode,
+          finishedAt: new anchor.BN(r.finishedAt),
+          index,
+          proof: merkleRootAndProof(leaves, index).proof.map((p) => Array.from(p)),
+        })
+        .accounts({ registry: registryPda, runBatch })
+        .rpc();
+    };
+    for (const [i, r] of receipts.entries()) await verify(i, r.exitCode);
+    await rejects(verify(1, 0), "InvalidProof");
+  });
+
+  it("refuses new runs on quarantined and retired modules", async () => {
+    const modulePda = await publish("gated-module");
+    const workerPda = await registerWorker(anchor.web3.Keypair.generate().publicKey);
+    const runLog = pda(Buffer.from("run_log"), registryPda.toBuffer(), modulePda.toBuffer());
+    await program.methods
+      .initRunLog({ capacity: 2 })
+      .accounts({ registry: registryPda, module: modulePda, runLog, authority, payer: authority, systemProgram })
+      .rpc();
+
+    const setStatus = (status: object) =>
+      program.methods
+        .setModuleStatus({ status })
+        .accounts({ registry: registryPda, module: modulePda, stake: stakeOf(authority), authority })
+        .rpc();
+
+    const inputs = sha25632(Buffer.from("gated-inputs"));
+    for (const [status, code] of [
+      [{ quarantined: {} }, "ModuleQuarantined"],
+      [{ retired: {} }, "ModuleRetired"],
+    ] as const) {
+      await setStatus(status);
+      await rejects(recordRun(modulePda, workerPda, inputs), code);
+      await rejects(logRuns(modulePda, workerPda, 1), code);
+      await rejects(commitRuns(modulePda, workerPda, 1), code);
+    }
+
+    await setStatus({ active: {} });
+    await recordRun(modulePda, workerPda, inputs);
+    await logRuns(modulePda, workerPda, 1);
+    await commitRuns(modulePda, workerPda, 1);
+    expect(((await program.account.module.fetch(modulePda)) as any).runCount.toNumber()).to.eq(3);
+  });
+
+  it("holds workers to their run window on every run path", async () => {
+    const modulePda = await publish("worker-window-module");
+    await program.methods
+      .initRunLog({ capacity: 4 })
+      .accounts({
+        registry: registryPda,
+        module: modulePda,
+        runLog: pda(Buffer.from("run_log"), registryPda.toBuffer(), modulePda.toBuffer()),
+        authority,
+        payer: authority,
+        systemProgram,
+      })
+      .rpc();
+
+    const basePolicy = ((await program.account.registry.fetch(registryPda)) as any).policy;
+    await program.methods
+      .setPolicy({ policy: { ...basePolicy, maxWorkerRunsPerWindow: 2 } })
+      .accounts({ registry: registryPda, authority })
+      .rpc();
+
+    try {
+      const inputs = sha25632(Buffer.from("window-inputs"));
+      const single = await registerWorker(anchor.web3.Keypair.generate().publicKey);
+      await recordRun(modulePda, single, inputs);
+      await recordRun(modulePda, single, inputs);
+      await rejects(recordRun(modulePda, single, inputs), "RunRateLimited");
+
+      const logger = await registerWorker(anchor.web3.Keypair.generate().publicKey);
+      await rejects(logRuns(modulePda, logger, 3), "RunRateLimited");
+      await logRuns(modulePda, logger, 2);
+      await rejects(logRuns(modulePda, logger, 1), "RunRateLimited");
+
+      const committer = await registerWorker(anchor.web3.Keypair.generate().publicKey);
+      await rejects(commitRuns(modulePda, committer, 3), "RunRateLimited");
+      await commitRuns(modulePda, committer, 2);
+      await rejects(commitRuns(modulePda, committer, 1), "RunRateLimited");
+
+      await rejects(logRuns(modulePda, null, 1), "WorkerRequired");
+      await rejects(commitRuns(modulePda, null, 1), "WorkerRequired");
+
+      for (const w of [single, logger, committer]) {
+        expect(((await program.account.worker.fetch(w)) as any).runCount.toNumber()).to.eq(2);
+      }
+    } finally {
+      await program.methods.setPolicy({ policy: basePolicy }).accounts({ registry: registryPda, authority }).rpc();
+    }
+  });
+
+  it("routes run fees up a promoted fork lineage as royalties", async () => {
+    const fee = 1_000_000;
+    const basePolicy = ((await program.account.registry.fetch(registryPda)) as any).policy;
+    const royaltyPolicy = {
+      ...basePolicy,
+      runFeeLamports: new anchor.BN(fee),
+      maxRoyaltyBps: 1000,
+      royaltyDepth: 2,
+      royaltyDecayBps: 5000,
+    };
+    const setPolicy = (policy: object) =>
+      program.methods.setPolicy({ policy }).accounts({ registry: registryPda, authority }).rpc();
+    await setPolicy(royaltyPolicy);
+
+    try {
+      const root = await publish("royalty-root", { royaltyBps: 1000 });
+      const mid = await publish("royalty-mid", { royaltyBps: 500, promote: { fork: await createFork(root), parent: root } });
+      const leaf = await publish("royalty-leaf", { promote: { fork: await createFork(mid), parent: mid } });
+      expect(((await program.account.module.fetch(leaf)) as any).lineageDepth).to.eq(2);
+
+      const workerPda = await registerWorker(anchor.web3.Keypair.generate().publicKey);
+      const inputs = sha25632(Buffer.from("royalty-inputs"));
+      const lineage = [mid, royaltyPda(mid), root, royaltyPda(root)];
+      const accrued = async (m: anchor.web3.PublicKey) =>
+        ((await program.account.royaltyBalance.fetch(royaltyPda(m))) as any).accrued.toNumber();
+
+      await rejects(recordRun(leaf, workerPda, inputs, true, { lineage: lineage.slice(0, 2) }), "InvalidLineage");
+      await rejects(recordRun(leaf, workerPda, inputs, true, { lineage: [...lineage.slice(2), ...lineage.slice(0, 2)] }), "InvalidLineage");
+
+      const treasuryBefore = await provider.connection.getBalance(treasuryPda);
+      await recordRun(leaf, workerPda, inputs, true, { lineage });
+      // The parent takes its 5%; the grandparent's 10% is halved once for the extra level.
+      expect(await accrued(mid)).to.eq(50_000);
+      expect(await accrued(root)).to.eq(50_000);
+      expect(await provider.connection.getBalance(treasuryPda)).to.eq(treasuryBefore + fee - 100_000);
+
+      // With the depth capped at one level only the parent is paid or needs to be passed.
+      await setPolicy({ ...royaltyPolicy, royaltyDepth: 1 });
+      await recordRun(leaf, workerPda, inputs, true, { lineage: lineage.slice(0, 2) });
+      expect(await accrued(mid)).to.eq(100_000);
+      expect(await accrued(root)).to.eq(50_000);
+
+      const recipient = await fundedKeypair();
+      const recipientBefore = await provider.connection.getBalance(recipient.publicKey);
+      const claim = () =>
+        program.methods
+          .claimRoyalties({ amount: null })
+          .accounts({
+            registry: registryPda,
+            module: mid,
+            royaltyBalance: royaltyPda(mid),
+            recipient: recipient.publicKey,
+            owner: authority,
+          })
+          .rpc();
+      await claim();
+      expect(await provider.connection.getBalance(recipient.publicKey)).to.eq(recipientBefore + 100_000);
+      expect(((await program.account.royaltyBalance.fetch(royaltyPda(mid))) as any).claimed.toNumber()).to.eq(100_000);
+      await rejects(claim(), "InvalidInput");
+    } finally {
+      await setPolicy(basePolicy);
+    }
+  });
+
+  it("stores small artifacts on chain in chunks checked against the published hash", async () => {
+    const artifact = crypto.randomBytes(40);
+    const modulePda = await publish("buffer-module", { artifact });
+    const artifactBuffer = pda(Buffer.from("artifact_buffer"), registryPda.toBuffer(), modulePda.toBuffer());
+    await program.methods
+      .initArtifactBuffer()
+      .accounts({ registry: registryPda, module: modulePda, artifactBuffer, authority, payer: authority, systemProgram })
+      .rpc();
+
+    const write = (offset: number, bytes: Buffer) =>
+      program.methods
+        .writeArtifactChunk({ offset, bytes })
+        .accounts({ registry: registryPda, module: modulePda, artifactBuffer, authority })
+        .rpc();
+    const finalize = () =>
+      program.methods.finalizeArtifact().accounts({ registry: registryPda, module: modulePda, artifactBuffer, authority }).rpc();
+
+    await write(0, artifact.subarray(0, 24));
+    await write(24, Buffer.alloc(16));
+    await rejects(finalize(), "ArtifactHashMismatch");
+    await rejects(write(32, Buffer.alloc(16)), "ArtifactTooLarge");
+
+    await write(24, artifact.subarray(24));
+    await finalize();
+    const buf: any = await program.account.artifactBuffer.fetch(artifactBuffer);
+    expect(buf.finalized).to.eq(1);
+    expect(buf.size).to.eq(artifact.length);
+    // Header: 8-byte discriminator plus the 80-byte ArtifactBuffer struct.
+    const info = await provider.connection.getAccountInfo(artifactBuffer);
+    expect(Buffer.compare(info!.data.subarray(88), artifact)).to.eq(0);
+    await rejects(write(0, artifact.subarray(0, 1)), "ArtifactFinalized");
+  });
+
+  it("adds per-target artifacts within the version and policy size limits", async () => {
+    const modulePda = await publish("multi-target-module");
+    const versionPda = pda(Buffer.from("version"), modulePda.toBuffer(), Buffer.from("0.1.0"));
+    const addArtifact = (target: string, size: number, version = "0.1.0") => {
+      const moduleVersion = pda(Buffer.from("version"), modulePda.toBuffer(), Buffer.from(version));
+      return program.methods
+        .addArtifact({
+          version,
+          target,
+          artifactSha256: sha25632(Buffer.from(`${target}-artifact`)),
+          artifactSize: new anchor.BN(size),
+          uri: "ipfs://example",
+          verified: true,
+        })
+        .accounts({
+          registry: registryPda,
+          module: modulePda,
+          moduleVersion,
+          artifact: pda(Buffer.from("artifact"), moduleVersion.toBuffer(), Buffer.from(target)),
+          authority,
+          payer: authority,
+          systemProgram,
+        })
+        .rpc();
+    };
+
+    const basePolicy = ((await program.account.registry.fetch(registryPda)) as any).policy;
+    await program.methods
+      .setPolicy({ policy: { ...basePolicy, maxVersionArtifactBytes: new anchor.BN(150) } })
+      .accounts({ registry: registryPda, authority })
+      .rpc();
+    try {
+      expect(await provider.connection.getAccountInfo(versionPda)).to.eq(null);
+      await addArtifact("linux-x64", 100);
+      const v: any = await program.account.moduleVersion.fetch(versionPda);
+      expect([v.version, v.artifactCount, v.artifactBytes.toNumber()]).to.deep.eq(["0.1.0", 1, 100]);
+      const a: any = await program.account.artifact.fetch(
+        pda(Buffer.from("artifact"), versionPda.toBuffer(), Buffer.from("linux-x64"))
+      );
+      expect([a.target, a.size.toNumber(), a.moduleVersion.toBase58()]).to.deep.eq(["linux-x64", 100, versionPda.toBase58()]);
+      expect(((await program.account.module.fetch(modulePda)) as any).attestationCount).to.eq(2);
+
+      await rejects(addArtifact("linux-x64", 10), "already in use");
+      await rejects(addArtifact("darwin-arm64", 51), "ArtifactTooLarge");
+      await rejects(addArtifact("wasm32", basePolicy.maxArtifactBytes.toNumber() + 1), "ArtifactTooLarge");
+      await rejects(addArtifact("Linux/x64", 10), "InvalidInput");
+      await rejects(addArtifact("linux-arm64", 10, "0.2.0"), "InvalidInput");
+      await addArtifact("darwin-arm64", 50);
+      const full: any = await program.account.moduleVersion.fetch(versionPda);
+      expect([full.artifactCount, full.artifactBytes.toNumber()]).to.deep.eq([2, 150]);
+    } finally {
+      await program.methods.setPolicy({ policy: basePolicy }).accounts({ registry: registryPda, authority }).rpc();
+    }
+  });
+
+  it("lets updates respell a module name only within the publish rules", async () => {
+    const modulePda = await publish("respell-module");
+    const update = (name: string) =>
+      program.methods
+        .updateModule({
+          name,
+          version: "0.1.1",
+          description: "respelled",
+          uri: "ipfs://example",
+          manifestSha256: sha25632(Buffer.from(name)),
+          manifest: null,
+        })
+        .accounts({ registry: registryPda, module: modulePda, authority })
+        .rpc();
+
+    await update("Respell_Module");
+    expect(((await program.account.module.fetch(modulePda)) as any).meta.name).to.eq("Respell_Module");
+    // Both normalize to the claimed name but fail the character rules publish applies.
+    await rejects(update("respell-module "), "InvalidInput");
+    await rejects(update(" respell-module"), "InvalidInput");
+  });
+
+  it("validates tags and categories through the instructions", async () => {
+    const modulePda = await publish("tagged-module");
+    const categoryOf = (slug: string) => pda(Buffer.from("category"), registryPda.toBuffer(), Buffer.from(slug));
+    const createCategory = (slug: string) =>
+      program.methods
+        .createCategory({ slug, description: `${slug} modules` })
+        .accounts({ registry: registryPda, category: categoryOf(slug), authority, payer: authority, systemProgram })
+        .rpc();
+    const setTags = (tags: string[], category: anchor.web3.PublicKey | null = null) =>
+      program.methods
+        .setModuleTags({ tags })
+        .accounts({ registry: registryPda, module: modulePda, category, authority })
+        .rpc();
+
+    await createCategory("dev-tools");
+    await rejects(createCategory("Dev Tools"), "InvalidInput");
+    await rejects(createCategory("dev-tools"), "already in use");
+
+    await setTags(["cli", "json"], categoryOf("dev-tools"));
+    let m: any = await program.account.module.fetch(modulePda);
+    expect(m.tags).to.deep.eq(["cli", "json"]);
+    expect(m.category.toBase58()).to.eq(categoryOf("dev-tools").toBase58());
+
+    await rejects(setTags(["CLI"]), "InvalidInput");
+    await rejects(setTags(["cli", "cli"]), "InvalidInput");
+    await rejects(setTags(["x".repeat(25)]), "InvalidInput");
+    await rejects(setTags(Array.from({ length: 9 }, (_, i) => `tag-${i}`)), "InvalidInput");
+
+    await setTags([]);
+    m = await program.account.module.fetch(modulePda);
+    expect(m.tags).to.deep.eq([]);
+    expect(m.category.toBase58()).to.eq(anchor.web3.PublicKey.default.toBase58());
+  });
+
+  it("numbers events in one gap-free sequence and carries the previous state", async () => {
+    const modulePda = await publish("evented-module");
+    const seqBefore = ((await program.account.registry.fetch(registryPda)) as any).eventSeq.toNumber();
+
+    const updated = await eventsOf(
+      await program.methods
+        .updateModule({
+          name: "evented-module",
+          version: "0.2.0",
+          description: "second release",
+          uri: "ipfs://example",
+          manifestSha256: sha25632(Buffer.from("evented-module-0.2.0")),
+          manifest: null,
+        })
+        .accounts({ registry: registryPda, module: modulePda, authority })
+        .rpc({ commitment: "confirmed" })
+    );
+    const tagged = await eventsOf(
+      await program.methods
+        .setModuleTags({ tags: ["events"] })
+        .accounts({ registry: registryPda, module: modulePda, category: null, authority })
+        .rpc({ commitment: "confirmed" })
+    );
+
+    const events = [...updated, ...tagged];
+    expect(events.map((e) => e.name)).to.deep.eq(["moduleUpdated", "moduleTagged"]);
+    expect(events.map((e) => e.data.eventSeq.toNumber())).to.deep.eq([seqBefore, seqBefore + 1]);
+    expect(((await program.account.registry.fetch(registryPda)) as any).eventSeq.toNumber()).to.eq(seqBefore + 2);
+
+    const [u, t] = events.map((e) => e.data as any);
+    expect([u.previousMeta.version, u.meta.version]).to.deep.eq(["0.1.0", "0.2.0"]);
+    expect([t.previousTags, t.tags]).to.deep.eq([[], ["events"]]);
+  });
+
+  it("collects lamport fees into the treasury and pays them out to the authority's recipient", async () => {
+    const basePolicy = ((await program.account.registry.fetch(registryPda)) as any).policy;
+    await program.methods
+      .setPolicy({ policy: { ...basePolicy, publishFeeLamports: new anchor.BN(5000) } })
+      .accounts({ registry: registryPda, authority })
+      .rpc();
+
+    try {
+      const treasuryBefore = await provider.connection.getBalance(treasuryPda);
+      await publish("fee-paying-module");
+      expect(await provider.connection.getBalance(treasuryPda)).to.eq(treasuryBefore + 5000);
+
+      const recipient = await fundedKeypair();
+      const withdraw = (amount: anchor.BN) =>
+        program.methods
+          .withdrawTreasury({ amount })
+          .accounts({ registry: registryPda, treasury: treasuryPda, recipient: recipient.publicKey, authority })
+          .rpc();
+      const withdrawnBefore = ((await program.account.treasury.fetch(treasuryPda)) as any).totalWithdrawn.toNumber();
+      const recipientBefore = await provider.connection.getBalance(recipient.publicKey);
+      await withdraw(new anchor.BN(5000));
+      expect(await provider.connection.getBalance(recipient.publicKey)).to.eq(recipientBefore + 5000);
+      expect(((await program.account.treasury.fetch(treasuryPda)) as any).totalWithdrawn.toNumber()).to.eq(withdrawnBefore + 5000);
+
+      // The rent-exempt reserve stays behind.
+      await rejects(withdraw(new anchor.BN(await provider.connection.getBalance(treasuryPda))), "InsufficientTreasury");
+      await rejects(withdraw(new anchor.BN(0)), "InvalidInput");
+    } finally {
+      await program.methods.setPolicy({ policy: basePolicy }).accounts({ registry: registryPda, authority }).rpc();
+    }
+  });
+
+  it("refuses unpinned forks of a yanked version until it is unyanked", async () => {
+    const modulePda = await publish("yank-module");
+    const moduleVersion = pda(Buffer.from("version"), modulePda.toBuffer(), Buffer.from("0.1.0"));
+    await program.methods
+      .yankVersion({ version: "0.1.0", reason: "broken build" })
+      .accounts({ registry: registryPda, module: modulePda, moduleVersion, authority, payer: authority, systemProgram })
+      .rpc();
+    const yanked: any = await program.account.moduleVersion.fetch(moduleVersion);
+    expect(yanked.yanked).to.eq(true);
+    expect(yanked.yankReason).to.eq("broken build");
+
+    const fork = (pinnedVersion: string | null) => {
+      const forkId = sha25632(crypto.randomBytes(32));
+      return program.methods
+        .createFork({ forkId, notes: "", pinnedVersion })
+        .accounts({
+          registry: registryPda,
+          module: modulePda,
+          fork: pda(Buffer.from("fork"), registryPda.toBuffer(), Buffer.from(forkId)),
+          moduleVersion,
+          rewardEpoch: null,
+          moduleUsage: null,
+          treasury: treasuryPda,
+          feeTokens: noFeeTokens,
+          authority,
+          payer: authority,
+          systemProgram,
+        })
+        .rpc();
+    };
+    await rejects(fork(null), "VersionYanked");
+    await fork("0.1.0");
+
+    await program.methods
+      .unyankVersion()
+      .accounts({ registry: registryPda, module: modulePda, moduleVersion, authority })
+      .rpc();
+    await fork(null);
+    expect(((await program.account.module.fetch(modulePda)) as any).forkCount.toNumber()).to.eq(2);
+  });
+
+  it("escrows bounties until a matching worker run claims them or they expire", async () => {
+    const modulePda = await publish("bounty-module");
+    const workerKp = anchor.web3.Keypair.generate();
+    const workerPda = await registerWorker(workerKp.publicKey);
+    const inputs = sha25632(Buffer.from("bounty-inputs"));
+    const reward = new anchor.BN(100_000_000);
+
+    const postBounty = async (expiresAt: number) => {
+      const bountyId = sha25632(crypto.randomBytes(32));
+      const bountyPda = pda(Buffer.from("bounty"), registryPda.toBuffer(), Buffer.from(bountyId));
+      await program.methods
+        .postBounty({ bountyId, version: "0.1.0", inputsSha256: inputs, reward, expiresAt: new anchor.BN(expiresAt), pinned: false })
+        .accounts({
+          registry: registryPda,
+          module: modulePda,
+          bounty: bountyPda,
+          moduleVersion: pda(Buffer.from("version"), modulePda.toBuffer(), Buffer.from("0.1.0")),
+          escrowTokens: noEscrowTokens,
+          poster: authority,
+          systemProgram,
+        })
+        .rpc();
+      return bountyPda;
+    };
+
+    // Claim: a successful run by a registered worker on the bounty's inputs.
+    const claimable = await postBounty((await chainNow()) + 3600);
+    const runPda = await recordRun(modulePda, workerPda, inputs);
+    await program.methods
+      .claimBounty()
+      .accounts({
+        registry: registryPda,
+        bounty: claimable,
+        run: runPda,
+        worker: workerPda,
+        recipient: workerKp.publicKey,
+        poster: authority,
+        escrowTokens: noEscrowTokens,
+      })
+      .rpc();
+    expect(await provider.connection.getBalance(workerKp.publicKey)).to.eq(reward.toNumber());
+    expect(await provider.connection.getAccountInfo(claimable)).to.eq(null);
+
+    // Cancel: refused while open, refunded after expiry.
+    const expiring = await postBounty((await chainNow()) + 2);
+    const cancel = () =>
+      program.methods
+        .cancelBounty()
+        .accounts({ registry: registryPda, bounty: expiring, escrowTokens: noEscrowTokens, poster: authority })
+        .rpc();
+    await rejects(cancel(), "BountyNotExpired");
+
+    await sleep(4000);
+    await cancel();
+    expect(await provider.connection.getAccountInfo(expiring)).to.eq(null);
+  });
+
+  it("locks publisher stake while modules are live and slashes into the treasury", async () => {
+    const sol = anchor.web3.LAMPORTS_PER_SOL;
+    const publisher = await fundedKeypair(2 * sol);
+    const stakePda = stakeOf(publisher.publicKey);
+    await program.methods
+      .depositStake({ amount: new anchor.BN(sol) })
+      .accounts({ registry: registryPda, stake: stakePda, publisher: publisher.publicKey, systemProgram })
+      .signers([publisher])
+      .rpc();
+
+    const basePolicy = ((await program.account.registry.fetch(registryPda)) as any).policy;
+    const minStake = sol / 2;
+    await program.methods
+      .setPolicy({ policy: { ...basePolicy, minPublishStake: new anchor.BN(minStake), unstakeCooldownSecs: 2 } })
+      .accounts({ registry: registryPda, authority })
+      .rpc();
+
+    try {
+      // The authority's own stake does not back modules it publishes for others.
+      await rejects(publish("unstaked-module"), "InsufficientStake");
+      const modulePda = await publish("stake-module", { publisher });
+      expect(((await program.account.module.fetch(modulePda)) as any).owner.toBase58()).to.eq(publisher.publicKey.toBase58());
+      let stake: any = await program.account.stake.fetch(stakePda);
+      expect(stake.liveModules).to.eq(1);
+
+      const requestUnstake = (amount: number) =>
+        program.methods
+          .requestUnstake({ amount: new anchor.BN(amount) })
+          .accounts({ registry: registryPda, stake: stakePda, publisher: publisher.publicKey })
+          .signers([publisher])
+          .rpc();
+      const withdrawStake = () =>
+        program.methods
+          .withdrawStake()
+          .accounts({ registry: registryPda, stake: stakePda, publisher: publisher.publicKey })
+          .signers([publisher])
+          .rpc();
+      await rejects(requestUnstake(sol - minStake + 1), "StakeLocked");
+
+      // Council attesters may only slash once the attestation has been revoked.
+      const council = await addAttester();
+      const slash = (slasher: anchor.web3.Keypair | null, amount: number) =>
+        program.methods
+          .slashPublisher({ amount: new anchor.BN(amount), reason: "malicious artifact" })
+          .accounts({
+            registry: registryPda,
+            module: modulePda,
+            stake: stakePda,
+            treasury: treasuryPda,
+            attester: slasher ? pda(Buffer.from("attester"), registryPda.toBuffer(), slasher.publicKey.toBuffer()) : null,
+            slasher: slasher ? slasher.publicKey : authority,
+          })
+          .signers(slasher ? [slasher] : [])
+          .rpc();
+      await rejects(slash(council, 1000), "AttestationNotRevoked");
+
+      const treasuryBefore = await provider.connection.getBalance(treasuryPda);
+      await slash(null, sol / 10);
+      expect(await provider.connection.getBalance(treasuryPda)).to.eq(treasuryBefore + sol / 10);
+      const slashed: any = await program.account.module.fetch(modulePda);
+      expect(slashed.verified).to.eq(false);
+      expect(slashed.attestationCount).to.eq(0);
+      await slash(council, 1000);
+
+      stake = await program.account.stake.fetch(stakePda);
+      expect(stake.amount.toNumber()).to.eq(sol - sol / 10 - 1000);
+
+      // Retiring releases the module's share of the lock.
+      await program.methods
+        .setModuleStatus({ status: { retired: {} } })
+        .accounts({ registry: registryPda, module: modulePda, stake: stakePda, authority })
+        .rpc();
+      stake = await program.account.stake.fetch(stakePda);
+      expect(stake.liveModules).to.eq(0);
+
+      await requestUnstake(stake.amount.toNumber());
+      await rejects(withdrawStake(), "UnstakeCooldown");
+      await sleep(3000);
+      const balanceBefore = await provider.connection.getBalance(publisher.publicKey);
+      await withdrawStake();
+      expect(((await program.account.stake.fetch(stakePda)) as any).amount.toNumber()).to.eq(0);
+      expect(await provider.connection.getBalance(publisher.publicKey)).to.be.greaterThan(balanceBefore);
+    } finally {
+      await program.methods.setPolicy({ policy: basePolicy }).accounts({ registry: registryPda, authority }).rpc();
+    }
+  });
+
+  it("pays mapped tokens to holders proving a snapshot leaf", async () => {
+    const payer = (provider.wallet as anchor.Wallet).payer;
+    const mint = await createMint(provider.connection, payer, authority, null, 0, undefined, undefined, TOKEN_2022_PROGRAM_ID);
+    const holder = anchor.web3.Keypair.generate();
+    const denied = anchor.web3.Keypair.generate();
+    const entries = [
+      { holder: authority, amount: 70 },
+      { holder: holder.publicKey, amount: 30 },
+      { holder: denied.publicKey, amount: 5 },
+    ];
+    const leaves = entries.map((e, i) => {
+      const index = Buffer.alloc(4);
+      index.writeUInt32LE(i);
+      const amount = Buffer.alloc(8);
+      amount.writeBigUInt64LE(BigInt(e.amount));
+      return merkleLeaf(Buffer.concat([Buffer.from("mapping"), index, e.holder.toBuffer(), amount]));
+    });
+    const { root } = merkleRootAndProof(leaves, 0);
+
+    const distributor = pda(Buffer.from("mapping"), registryPda.toBuffer(), mint.toBuffer());
+    const vault = getAssociatedTokenAddressSync(mint, distributor, true, TOKEN_2022_PROGRAM_ID);
+    await program.methods
+      .initMappingDistributor({
+        merkleRoot: Array.from(root),
+        snapshotSlot: new anchor.BN(await provider.connection.getSlot()),
+        numLeaves: entries.length,
+        totalAmount: new anchor.BN(105),
+        denylist: [denied.publicKey],
+      })
+      .accounts({
+        registry: registryPda,
+        distributor,
+        mint,
+        vault,
+        authority,
+        payer: authority,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+        systemProgram,
+      })
+      .rpc();
+    await mintTo(provider.connection, payer, mint, vault, authority, 105, [], undefined, TOKEN_2022_PROGRAM_ID);
+
+    const claim = async (index: number, signer: anchor.web3.Keypair | null) => {
+      const owner = signer ? signer.publicKey : authority;
+      const account = await getOrCreateAssociatedTokenAccount(
+        provider.connection, payer, mint, owner, false, undefined, undefined, TOKEN_2022_PROGRAM_ID
+      );
+      await program.methods
+        .claimMappedTokens({
+          index,
+          amount: new anchor.BN(entries[index].amount),
+          proof: merkleRootAndProof(leaves, index).proof.map((p) => Array.from(p)),
+        })
+        .accounts({
+          registry: registryPda,
+          distributor,
+          mint,
+          vault,
+          holderTokenAccount: account.address,
+          holder: owner,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers(signer ? [signer] : [])
+        .rpc();
+      return account.address;
+    };
+
+    const holderAccount = await claim(1, holder);
+    expect(Number((await getAccount(provider.connection, holderAccount, undefined, TOKEN_2022_PROGRAM_ID)).amount)).to.eq(30);
+    await rejects(claim(1, holder), "AlreadyClaimed");
+    await rejects(claim(2, denied), "Denylisted");
+    await claim(0, null);
+
+    const d: any = await program.account.mappingDistributor.fetch(distributor);
+    expect(d.claimedAmount.toNumber()).to.eq(100);
+    expect(d.claimedCount).to.eq(2);
+  });
+
+  it("escrows sponsorships and releases scheduled installments to the module", async () => {
+    const modulePda = await publish("sponsored-module");
+    const sponsor = await fundedKeypair();
+    const escrow = pda(Buffer.from("sponsor_escrow"), modulePda.toBuffer());
+    const sponsorship = pda(Buffer.from("sponsorship"), modulePda.toBuffer(), sponsor.publicKey.toBuffer());
+    const sponsorModule = (amount: number, schedule: { periodSecs: number; periods: number } | null) =>
+      program.methods
+        .sponsorModule({ amount: new anchor.BN(amount), schedule })
+        .accounts({
+          registry: registryPda,
+          module: modulePda,
+          escrow,
+          sponsorship,
+          escrowTokens: noEscrowTokens,
+          sponsor: sponsor.publicKey,
+          systemProgram,
+        })
+        .signers([sponsor])
+        .rpc();
+    const release = () =>
+      program.methods
+        .releaseSponsorship()
+        .accounts({ registry: registryPda, escrow, sponsorship, escrowTokens: noEscrowTokens })
+        .rpc();
+
+    const tip = 2_000_000;
+    const installment = 1_000_000;
+    await sponsorModule(tip, null);
+    await sponsorModule(installment, { periodSecs: 3600, periods: 3 });
+
+    // The first installment is due at once; the next only after a period.
+    await release();
+    await rejects(release(), "SponsorshipNotDue");
+    let s: any = await program.account.sponsorship.fetch(sponsorship);
+    expect(s.totalLamports.toNumber()).to.eq(tip + installment);
+    expect(s.schedule.remainingPeriods).to.eq(2);
+
+    const before = await provider.connection.getBalance(sponsor.publicKey);
+    await program.methods
+      .cancelSponsorship()
+      .accounts({ registry: registryPda, sponsorship, escrowTokens: noEscrowTokens, sponsor: sponsor.publicKey })
+      .signers([sponsor])
+      .rpc();
+    expect(await provider.connection.getBalance(sponsor.publicKey)).to.eq(before + 2 * installment);
+    s = await program.account.sponsorship.fetch(sponsorship);
+    expect(s.schedule).to.eq(null);
+
+    await program.methods
+      .withdrawSponsorship({ amount: new anchor.BN(tip + installment) })
+      .accounts({ registry: registryPda, module: modulePda, escrow, escrowTokens: noEscrowTokens, owner: authority })
+      .rpc();
+    const e: any = await program.account.sponsorEscrow.fetch(escrow);
+    expect(e.lamportsReceived.toNumber()).to.eq(tip + installment);
+    expect(e.lamportsWithdrawn.toNumber()).to.eq(tip + installment);
+  });
+
+  it("aggregates one replaceable review per reviewer and version", async () => {
+    const modulePda = await publish("reviewed-module");
+    const versionOf = (version: string) => pda(Buffer.from("version"), modulePda.toBuffer(), Buffer.from(version));
+    const reviewOf = (reviewer: anchor.web3.Keypair, version = "0.1.0") =>
+      pda(Buffer.from("review"), versionOf(version).toBuffer(), reviewer.publicKey.toBuffer());
+    const versionPda = versionOf("0.1.0");
+    const review = (reviewer: anchor.web3.Keypair, rating: number, run: anchor.web3.PublicKey | null = null, version = "0.1.0") =>
+      program.methods
+        .submitReview({ version, rating, flags: 0, commentSha256: zero32 })
+        .accounts({
+          registry: registryPda,
+          module: modulePda,
+          moduleVersion: versionOf(version),
+          review: reviewOf(reviewer, version),
+          run,
+          reviewer: reviewer.publicKey,
+          systemProgram,
+        })
+        .signers([reviewer])
+        .rpc();
+    const aggregates = async () => {
+      const m: any = await program.account.module.fetch(modulePda);
+      return [m.reviewCount, m.ratingTotal.toNumber()];
+    };
+
+    // The first review creates the state of a version nothing else has touched yet.
+    const [first, second] = [await fundedKeypair(), await fundedKeypair()];
+    expect(await provider.connection.getAccountInfo(versionPda)).to.eq(null);
+    await review(first, 5);
+    expect(((await program.account.moduleVersion.fetch(versionPda)) as any).version).to.eq("0.1.0");
+    await review(second, 2);
+    expect(await aggregates()).to.deep.eq([2, 7]);
+
+    await review(first, 3);
+    expect(await aggregates()).to.deep.eq([2, 5]);
+    expect(((await program.account.review.fetch(reviewOf(first))) as any).rating).to.eq(3);
+    await rejects(review(second, 6), "InvalidInput");
+    await rejects(review(second, 4, null, "9.9.9"), "InvalidInput");
+
+    const basePolicy = ((await program.account.registry.fetch(registryPda)) as any).policy;
+    await program.methods
+      .setPolicy({ policy: { ...basePolicy, reviewRequiresRun: true } })
+      .accounts({ registry: registryPda, authority })
+      .rpc();
+    try {
+      const runner = await fundedKeypair();
+      await rejects(review(runner, 4), "ReviewRequiresRun");
+      const run = await recordRun(modulePda, await registerWorker(runner.publicKey), sha25632(Buffer.from("review-inputs")));
+      await rejects(review(second, 4, run), "ReviewRequiresRun");
+      await review(runner, 4, run);
+      expect(await aggregates()).to.deep.eq([3, 9]);
+    } finally {
+      await program.methods.setPolicy({ policy: basePolicy }).accounts({ registry: registryPda, authority }).rpc();
+    }
+  });
+
+  it("accrues epoch usage points and pays claims pro rata", async () => {
+    const basePolicy = ((await program.account.registry.fetch(registryPda)) as any).policy;
+    await program.methods
+      .setPolicy({ policy: { ...basePolicy, forkFeeLamports: new anchor.BN(1000) } })
+      .accounts({ registry: registryPda, authority })
+      .rpc();
+
+    const [ownerA, ownerB, forker] = [await fundedKeypair(), await fundedKeypair(), await fundedKeypair()];
+    const moduleA = await publish("epoch-module-a", { publisher: ownerA });
+    const moduleB = await publish("epoch-module-b", { publisher: ownerB });
+    const workerPda = await registerWorker(anchor.web3.Keypair.generate().publicKey);
+    const inputs = sha25632(Buffer.from("epoch-inputs"));
+
+    const index: anchor.BN = ((await program.account.registry.fetch(registryPda)) as any).epochCount;
+    const epochPda = pda(Buffer.from("epoch"), registryPda.toBuffer(), index.toArrayLike(Buffer, "le", 8));
+    const usageOf = (m: anchor.web3.PublicKey) => pda(Buffer.from("usage"), epochPda.toBuffer(), m.toBuffer());
+    const epochFor = (m: anchor.web3.PublicKey) => ({ rewardEpoch: epochPda, moduleUsage: usageOf(m) });
+    const pointsOf = async (m: anchor.web3.PublicKey) =>
+      ((await program.account.moduleUsage.fetch(usageOf(m))) as any).points.toNumber();
+    const pool = 600_000;
+
+    try {
+      await program.methods
+        .openRewardEpoch({ durationSecs: 10, pool: new anchor.BN(pool), runPoints: 1, forkPoints: 3, claimWindowSecs: 60 })
+        .accounts({ registry: registryPda, rewardEpoch: epochPda, previousEpoch: null, authority, systemProgram })
+        .rpc();
+
+      await recordRun(moduleA, workerPda, inputs, true, { epoch: epochFor(moduleA) });
+      await recordRun(moduleA, workerPda, inputs, true, { epoch: epochFor(moduleA) });
+      await recordRun(moduleB, workerPda, inputs, true, { epoch: epochFor(moduleB) });
+      await rejects(recordRun(moduleB, workerPda, inputs), "InvalidInput");
+      // A paid fork by someone else earns fork points; forking one's own module earns nothing.
+      await createFork(moduleA, forker, epochFor(moduleA));
+      await createFork(moduleA, ownerA);
+      expect([await pointsOf(moduleA), await pointsOf(moduleB)]).to.deep.eq([5, 1]);
+      expect(((await program.account.rewardEpoch.fetch(epochPda)) as any).totalPoints.toNumber()).to.eq(6);
+
+      const claim = (m: anchor.web3.PublicKey, owner: anchor.web3.Keypair) =>
+        program.methods
+          .claimEpochReward()
+          .accounts({ registry: registryPda, rewardEpoch: epochPda, module: m, moduleUsage: usageOf(m), owner: owner.publicKey })
+          .rpc();
+      await rejects(claim(moduleA, ownerA), "EpochOpen");
+
+      const endAt = ((await program.account.rewardEpoch.fetch(epochPda)) as any).endAt.toNumber();
+      while ((await chainNow()) < endAt) await sleep(500);
+
+      for (const [m, owner, reward] of [
+        [moduleA, ownerA, (pool * 5) / 6],
+        [moduleB, ownerB, pool / 6],
+      ] as const) {
+        const before = await provider.connection.getBalance(owner.publicKey);
+        await claim(m, owner);
+        expect(await provider.connection.getBalance(owner.publicKey)).to.eq(before + reward);
+      }
+      await rejects(claim(moduleA, ownerA), "AlreadyClaimed");
+      expect(((await program.account.rewardEpoch.fetch(epochPda)) as any).claimedAmount.toNumber()).to.eq(pool);
+
+      // Usage accounts are refused once the epoch has ended; the first run after it retires the epoch.
+      await rejects(recordRun(moduleA, workerPda, inputs, true, { epoch: epochFor(moduleA) }), "EpochClosed");
+      await recordRun(moduleA, workerPda, inputs, true, { epoch: { rewardEpoch: epochPda, moduleUsage: null } });
+      const reg: any = await program.account.registry.fetch(registryPda);
+      expect(reg.activeEpoch.toBase58()).to.eq(anchor.web3.PublicKey.default.toBase58());
+
+      await rejects(
+        program.methods.closeRewardEpoch().accounts({ registry: registryPda, rewardEpoch: epochPda, authority }).rpc(),
+        "EpochOpen"
+      );
+    } finally {
+      await program.methods.setPolicy({ policy: basePolicy }).accounts({ registry: registryPda, authority }).rpc();
+    }
   });
 });